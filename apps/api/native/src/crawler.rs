@@ -1,10 +1,11 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
   collections::{HashMap, HashSet},
-  sync::LazyLock,
+  sync::{LazyLock, Mutex},
 };
 use texting_robots::Robot;
 use tokio::task;
@@ -19,6 +20,13 @@ static FILE_EXTENSIONS: &[&str] = &[
 static FILE_EXT_SET: LazyLock<HashSet<&'static str>> =
   LazyLock::new(|| FILE_EXTENSIONS.iter().copied().collect());
 
+const PATTERN_CACHE_CAPACITY: usize = 4096;
+
+/// Compiled include/exclude patterns, shared across calls so a crawl's patterns are only compiled
+/// once instead of on every `filter_links` call.
+static PATTERN_CACHE: LazyLock<Mutex<HashMap<String, Regex>>> =
+  LazyLock::new(|| Mutex::new(HashMap::new()));
+
 #[derive(Deserialize)]
 #[napi(object)]
 pub struct FilterLinksCall {
@@ -35,6 +43,9 @@ pub struct FilterLinksCall {
   pub robots_txt: String,
   pub allow_external_content_links: bool,
   pub allow_subdomains: bool,
+  /// Deny internal links that look like crawler traps (session IDs, repeating path segments,
+  /// calendar pages, faceted navigation with many query parameters).
+  pub detect_crawler_traps: Option<bool>,
 }
 
 #[derive(Serialize)]
@@ -42,6 +53,8 @@ pub struct FilterLinksCall {
 pub struct FilterLinksResult {
   pub links: Vec<String>,
   pub denial_reasons: HashMap<String, String>,
+  /// Number of denied links per denial reason, for crawl skip statistics.
+  pub denial_counts: HashMap<String, u32>,
 }
 
 #[derive(Deserialize)]
@@ -65,10 +78,21 @@ pub struct FilterUrlResult {
   pub denial_reason: Option<String>,
 }
 
+#[derive(Serialize, Debug)]
+#[napi(object)]
+pub struct PatternError {
+  pub pattern: String,
+  pub error: String,
+}
+
 #[derive(Serialize, Debug)]
 #[napi(object)]
 pub struct SitemapUrl {
   pub loc: Vec<String>,
+  pub lastmod: Option<String>,
+  /// Relative priority between 0.0 and 1.0, as declared by the site.
+  pub priority: Option<f64>,
+  pub changefreq: Option<String>,
 }
 
 #[derive(Serialize, Debug)]
@@ -111,6 +135,8 @@ pub struct SitemapProcessingResult {
   pub total_count: u32,
 }
 
+const DEFAULT_SITEMAP_PRIORITY: f64 = 0.5;
+
 const URL_PARSE_ERROR: &str = "URL_PARSE_ERROR";
 const DEPTH_LIMIT: &str = "DEPTH_LIMIT";
 const EXCLUDE_PATTERN: &str = "EXCLUDE_PATTERN";
@@ -122,6 +148,21 @@ const SOCIAL_MEDIA: &str = "SOCIAL_MEDIA";
 const EXTERNAL_LINK: &str = "EXTERNAL_LINK";
 const SECTION_LINK: &str = "SECTION_LINK";
 const NON_WEB_PROTOCOL: &str = "NON_WEB_PROTOCOL";
+const CRAWLER_TRAP: &str = "CRAWLER_TRAP";
+const LIMIT: &str = "LIMIT";
+
+fn count_denials(denial_reasons: &HashMap<String, String>) -> HashMap<String, u32> {
+  let mut counts = HashMap::new();
+  for reason in denial_reasons.values() {
+    *counts.entry(reason.clone()).or_insert(0) += 1;
+  }
+  counts
+}
+
+const SESSION_ID_PARAMS: &[&str] = &["sessionid", "session_id", "sid", "jsessionid", "phpsessid"];
+const CALENDAR_PARAMS: &[&str] = &["year", "month", "week", "day", "date"];
+const MAX_TRAP_QUERY_PARAMS: usize = 5;
+const MAX_REPEATED_SEGMENTS: usize = 2;
 
 #[inline]
 fn is_file(path: &str) -> bool {
@@ -214,6 +255,37 @@ fn is_subdomain(url: &Url, base_url: &Url) -> bool {
   }
 }
 
+fn is_crawler_trap(url: &Url) -> bool {
+  let path = url.path().to_lowercase();
+  if path.contains(";jsessionid=") {
+    return true;
+  }
+
+  let mut segment_counts: HashMap<&str, usize> = HashMap::new();
+  for segment in path.split('/').filter(|s| !s.is_empty()) {
+    let count = segment_counts.entry(segment).or_default();
+    *count += 1;
+    if *count > MAX_REPEATED_SEGMENTS {
+      return true;
+    }
+  }
+
+  let keys: Vec<String> = url
+    .query_pairs()
+    .map(|(key, _)| key.to_lowercase())
+    .collect();
+
+  if keys.iter().any(|k| SESSION_ID_PARAMS.contains(&k.as_str())) {
+    return true;
+  }
+
+  if path.contains("calendar") && keys.iter().any(|k| CALENDAR_PARAMS.contains(&k.as_str())) {
+    return true;
+  }
+
+  keys.len() > MAX_TRAP_QUERY_PARAMS
+}
+
 #[inline]
 fn is_external_main_page(url_str: &str) -> bool {
   if let Ok(url) = Url::parse(url_str) {
@@ -227,12 +299,96 @@ fn is_external_main_page(url_str: &str) -> bool {
   }
 }
 
+/// Compiles an include/exclude pattern. Patterns prefixed with `glob:` use shell-style globbing
+/// (`*` within a path segment, `**` across segments, `?` for a single character) and must match
+/// the whole target; anything else is treated as a regular expression, as before.
+fn compile_pattern(pattern: &str) -> std::result::Result<Regex, String> {
+  if let Some(regex) = PATTERN_CACHE
+    .lock()
+    .ok()
+    .and_then(|cache| cache.get(pattern).cloned())
+  {
+    return Ok(regex);
+  }
+
+  let regex = match pattern.strip_prefix("glob:") {
+    Some(glob) => Regex::new(&glob_to_regex(glob)),
+    None => Regex::new(pattern),
+  }
+  .map_err(|e| e.to_string())?;
+
+  if let Ok(mut cache) = PATTERN_CACHE.lock() {
+    if cache.len() >= PATTERN_CACHE_CAPACITY {
+      cache.clear();
+    }
+    cache.insert(pattern.to_string(), regex.clone());
+  }
+
+  Ok(regex)
+}
+
+fn glob_to_regex(glob: &str) -> String {
+  let mut regex = String::from("^");
+  let mut chars = glob.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        // `**/` also matches zero segments, so `/a/**/b` matches `/a/b`
+        if chars.peek() == Some(&'/') {
+          chars.next();
+          regex.push_str("(?:.*/)?");
+        } else {
+          regex.push_str(".*");
+        }
+      }
+      '*' => regex.push_str("[^/]*"),
+      '?' => regex.push_str("[^/]"),
+      _ => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+    }
+  }
+  regex.push('$');
+
+  regex
+}
+
+fn _validate_patterns(patterns: &[String]) -> Vec<PatternError> {
+  patterns
+    .iter()
+    .filter_map(|pattern| {
+      compile_pattern(pattern).err().map(|error| PatternError {
+        pattern: pattern.clone(),
+        error,
+      })
+    })
+    .collect()
+}
+
+/// Validate include/exclude patterns, returning an entry for every pattern that fails to compile.
+#[napi]
+pub async fn validate_patterns(patterns: Vec<String>) -> Result<Vec<PatternError>> {
+  task::spawn_blocking(move || _validate_patterns(&patterns))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("validate_patterns join error: {e}"),
+      )
+    })
+}
+
 fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult, String> {
   let limit = data.limit.map_or(usize::MAX, |x| x.max(0) as usize);
   if limit == 0 {
+    let denial_reasons: HashMap<String, String> = data
+      .links
+      .into_iter()
+      .map(|link| (link, LIMIT.to_string()))
+      .collect();
     return Ok(FilterLinksResult {
       links: Vec::new(),
-      denial_reasons: HashMap::new(),
+      denial_counts: count_denials(&denial_reasons),
+      denial_reasons,
     });
   }
 
@@ -244,12 +400,12 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
   let excludes_regex: Vec<Regex> = data
     .excludes
     .iter()
-    .filter_map(|e| Regex::new(e).ok())
+    .filter_map(|e| compile_pattern(e).ok())
     .collect();
   let includes_regex: Vec<Regex> = data
     .includes
     .iter()
-    .filter_map(|i| Regex::new(i).ok())
+    .filter_map(|i| compile_pattern(i).ok())
     .collect();
 
   let robot = if !data.ignore_robots_txt && !data.robots_txt.is_empty() {
@@ -265,7 +421,8 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
 
   for link in data.links {
     if result_links.len() >= limit {
-      break;
+      denial_reasons.insert(link, LIMIT.to_string());
+      continue;
     }
 
     let url = match base_url.join(&link) {
@@ -322,6 +479,11 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
         continue;
       }
 
+      if data.detect_crawler_traps == Some(true) && is_crawler_trap(&url) {
+        denial_reasons.insert(link, CRAWLER_TRAP.to_string());
+        continue;
+      }
+
       if let Some(ref robot) = robot {
         if !robot.allowed(url_str) {
           denial_reasons.insert(link, ROBOTS_TXT.to_string());
@@ -374,6 +536,7 @@ fn _filter_links(data: FilterLinksCall) -> std::result::Result<FilterLinksResult
 
   Ok(FilterLinksResult {
     links: result_links,
+    denial_counts: count_denials(&denial_reasons),
     denial_reasons,
   })
 }
@@ -455,7 +618,7 @@ fn _filter_url(data: FilterUrlCall) -> std::result::Result<FilterUrlResult, Stri
   let excludes_regex: Vec<Regex> = data
     .excludes
     .iter()
-    .filter_map(|e| Regex::new(e).ok())
+    .filter_map(|e| compile_pattern(e).ok())
     .collect();
 
   let robot = if !data.ignore_robots_txt && !data.robots_txt.is_empty() {
@@ -571,7 +734,33 @@ pub async fn filter_url(data: FilterUrlCall) -> Result<FilterUrlResult> {
   res.map_err(|e| Error::new(Status::GenericFailure, format!("Filter URL error: {e}")))
 }
 
+/// Finds a `<urlset>` or `<sitemapindex>` document embedded in other markup, e.g. an HTML page
+/// produced by a browser's XML viewer or a CMS template wrapping the sitemap.
+fn embedded_sitemap_root(content: &str) -> Option<&str> {
+  ["urlset", "sitemapindex"].iter().find_map(|tag| {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let start = content.match_indices(&open).find_map(|(i, _)| {
+      content[i + open.len()..]
+        .starts_with(|c: char| c == '>' || c.is_ascii_whitespace())
+        .then_some(i)
+    })?;
+    let end = content.rfind(&close)? + close.len();
+    (start < end).then(|| &content[start..end])
+  })
+}
+
+/// Parses sitemap XML, tolerating a leading BOM or whitespace and sitemaps wrapped in HTML.
 fn _parse_sitemap_xml(xml_content: &str) -> std::result::Result<ParsedSitemap, String> {
+  let xml_content = xml_content.trim_start_matches('\u{feff}').trim_start();
+
+  _parse_sitemap_document(xml_content).or_else(|e| match embedded_sitemap_root(xml_content) {
+    Some(embedded) if embedded.len() < xml_content.len() => _parse_sitemap_document(embedded),
+    _ => Err(e),
+  })
+}
+
+fn _parse_sitemap_document(xml_content: &str) -> std::result::Result<ParsedSitemap, String> {
   let doc = roxmltree::Document::parse_with_options(
     xml_content,
     roxmltree::ParsingOptions {
@@ -608,13 +797,21 @@ fn _parse_sitemap_xml(xml_content: &str) -> std::result::Result<ParsedSitemap, S
         .children()
         .filter(|n| n.is_element() && n.tag_name().name() == "url")
         .filter_map(|url_node| {
-          url_node
-            .children()
-            .find(|n| n.is_element() && n.tag_name().name() == "loc")
-            .and_then(|loc_node| loc_node.text())
-            .map(|loc_text| SitemapUrl {
-              loc: vec![loc_text.to_string()],
-            })
+          let child_text = |name: &str| {
+            url_node
+              .children()
+              .find(|n| n.is_element() && n.tag_name().name() == name)
+              .and_then(|n| n.text())
+          };
+
+          child_text("loc").map(|loc_text| SitemapUrl {
+            loc: vec![loc_text.to_string()],
+            lastmod: child_text("lastmod").map(|l| l.trim().to_string()),
+            priority: child_text("priority")
+              .and_then(|p| p.trim().parse::<f64>().ok())
+              .filter(|p| (0.0..=1.0).contains(p)),
+            changefreq: child_text("changefreq").map(|c| c.trim().to_lowercase()),
+          })
         })
         .collect();
 
@@ -647,6 +844,25 @@ pub async fn parse_sitemap_xml(xml_content: String) -> Result<ParsedSitemap> {
   })
 }
 
+/// Parses a sitemap `lastmod`, which uses the W3C Datetime profile of ISO 8601: a full timestamp
+/// with a time zone, one without seconds, or a plain date. Timestamps without a time zone are
+/// taken as UTC.
+fn parse_lastmod(lastmod: &str) -> Option<DateTime<Utc>> {
+  if let Ok(modified) = DateTime::parse_from_rfc3339(lastmod) {
+    return Some(modified.to_utc());
+  }
+  if let Ok(modified) = DateTime::parse_from_str(lastmod, "%Y-%m-%dT%H:%M%#z") {
+    return Some(modified.to_utc());
+  }
+  if let Ok(modified) = NaiveDateTime::parse_from_str(lastmod, "%Y-%m-%dT%H:%M:%S%.f") {
+    return Some(modified.and_utc());
+  }
+  NaiveDate::parse_from_str(lastmod, "%Y-%m-%d")
+    .ok()
+    .and_then(|date| date.and_hms_opt(0, 0, 0))
+    .map(|modified| modified.and_utc())
+}
+
 fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingResult, String> {
   let parsed = _parse_sitemap_xml(xml_content)?;
   let mut instructions = Vec::new();
@@ -687,7 +903,13 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
         } else if let Ok(parsed_url) = Url::parse(url) {
           let path_lower = parsed_url.path().to_lowercase();
           if !is_file(&path_lower) {
-            valid_urls.push(url.to_string());
+            let modified = url_entry.lastmod.as_deref().and_then(parse_lastmod);
+            valid_urls.push((
+              url.to_string(),
+              url_entry.priority,
+              url_entry.lastmod,
+              modified,
+            ));
           }
         }
       }
@@ -703,6 +925,21 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
       total_count += count;
     }
 
+    // Highest priority first, most recently modified first within a priority, so that a caller
+    // capping the list keeps the pages the site considers most important. Missing priorities default to 0.5 as
+    // per the sitemap protocol. Dates that parse come before those that don't, which are only
+    // compared as strings; the sort is stable, so ties keep document order.
+    valid_urls.sort_by(|a, b| {
+      let (_, a_priority, a_lastmod, a_modified) = a;
+      let (_, b_priority, b_lastmod, b_modified) = b;
+      b_priority
+        .unwrap_or(DEFAULT_SITEMAP_PRIORITY)
+        .total_cmp(&a_priority.unwrap_or(DEFAULT_SITEMAP_PRIORITY))
+        .then_with(|| b_modified.cmp(a_modified))
+        .then_with(|| b_lastmod.cmp(a_lastmod))
+    });
+    let valid_urls: Vec<String> = valid_urls.into_iter().map(|(url, _, _, _)| url).collect();
+
     if !valid_urls.is_empty() {
       let count = valid_urls.len() as u32;
       instructions.push(SitemapInstruction {
@@ -721,6 +958,9 @@ fn _process_sitemap(xml_content: &str) -> std::result::Result<SitemapProcessingR
 }
 
 /// Process sitemap XML and extract crawling instructions.
+///
+/// Page URLs are ordered by priority, then by last modification, so callers that cap the number
+/// of URLs taken from one sitemap (after their own filtering) keep the most important pages.
 #[napi]
 pub async fn process_sitemap(xml_content: String) -> Result<SitemapProcessingResult> {
   let res = task::spawn_blocking(move || _process_sitemap(&xml_content))
@@ -812,6 +1052,39 @@ mod tests {
     assert!(result.is_err());
   }
 
+  #[test]
+  fn test_parse_sitemap_xml_bom_and_leading_whitespace() {
+    let xml_content = "\u{feff}\n\n  <?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">
+  <url><loc>https://example.com/page1</loc></url>
+</urlset>";
+
+    let result = _parse_sitemap_xml(xml_content).unwrap();
+    let urlset = result.urlset.unwrap();
+    assert_eq!(urlset.url.len(), 1);
+    assert_eq!(urlset.url[0].loc[0], "https://example.com/page1");
+  }
+
+  #[test]
+  fn test_parse_sitemap_xml_html_wrapped() {
+    let xml_content = r#"<html><head><title>Sitemap</title></head><body>
+<div id="webkit-xml-viewer-source-xml"><sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://example.com/sitemap1.xml</loc></sitemap>
+</sitemapindex></div>
+</body></html>"#;
+
+    let result = _parse_sitemap_xml(xml_content).unwrap();
+    let sitemapindex = result.sitemapindex.unwrap();
+    assert_eq!(sitemapindex.sitemap.len(), 1);
+    assert_eq!(
+      sitemapindex.sitemap[0].loc[0],
+      "https://example.com/sitemap1.xml"
+    );
+
+    let not_a_sitemap = "<html><body><p>No sitemap here</p></body></html>";
+    assert!(_parse_sitemap_xml(not_a_sitemap).is_err());
+  }
+
   #[test]
   fn test_process_sitemap_urlset() {
     let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -876,6 +1149,73 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_process_sitemap_priority_and_lastmod() {
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/old</loc><lastmod>2023-01-01</lastmod></url>
+  <url><loc>https://example.com/low</loc><priority>0.1</priority></url>
+  <url>
+    <loc>https://example.com/home</loc>
+    <lastmod> 2024-05-01T10:00:00+00:00 </lastmod>
+    <changefreq>Daily</changefreq>
+    <priority>1.0</priority>
+  </url>
+  <url><loc>https://example.com/new</loc><lastmod>2024-03-01</lastmod></url>
+  <url><loc>https://example.com/undated</loc><priority>7</priority></url>
+</urlset>"#;
+
+    let parsed = _parse_sitemap_xml(xml_content).unwrap().urlset.unwrap();
+    let home = &parsed.url[2];
+    assert_eq!(home.lastmod.as_deref(), Some("2024-05-01T10:00:00+00:00"));
+    assert_eq!(home.priority, Some(1.0));
+    assert_eq!(home.changefreq.as_deref(), Some("daily"));
+    assert_eq!(parsed.url[4].priority, None);
+
+    let result = _process_sitemap(xml_content).unwrap();
+    assert_eq!(
+      result.instructions[0].urls,
+      vec![
+        "https://example.com/home",
+        "https://example.com/new",
+        "https://example.com/old",
+        "https://example.com/undated",
+        "https://example.com/low",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_process_sitemap_lastmod_order() {
+    let xml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/utc</loc><lastmod>2024-03-02T01:00:00+00:00</lastmod></url>
+  <url><loc>https://example.com/bogus</loc><lastmod>yesterday</lastmod></url>
+  <url><loc>https://example.com/eastern</loc><lastmod>2024-03-01T23:00:00-05:00</lastmod></url>
+  <url><loc>https://example.com/minutes</loc><lastmod>2024-03-02T02:30Z</lastmod></url>
+  <url><loc>https://example.com/date</loc><lastmod>2024-03-01</lastmod></url>
+</urlset>"#;
+
+    let result = _process_sitemap(xml_content).unwrap();
+    assert_eq!(
+      result.instructions[0].urls,
+      vec![
+        "https://example.com/eastern",
+        "https://example.com/minutes",
+        "https://example.com/utc",
+        "https://example.com/date",
+        "https://example.com/bogus",
+      ]
+    );
+
+    assert_eq!(
+      parse_lastmod("2024-03-01"),
+      parse_lastmod("2024-03-01T00:00:00Z")
+    );
+    assert!(parse_lastmod("2024-03-01T12:00:00.123").is_some());
+    assert!(parse_lastmod("yesterday").is_none());
+  }
+
   #[test]
   fn test_filter_links_normal_robots_txt() {
     let data = FilterLinksCall {
@@ -895,6 +1235,7 @@ mod tests {
       allow_backward_crawling: true,
       allow_external_content_links: false,
       allow_subdomains: false,
+      detect_crawler_traps: None,
     };
 
     let result = _filter_links(data).unwrap();
@@ -929,6 +1270,7 @@ mod tests {
       allow_backward_crawling: true,
       allow_external_content_links: false,
       allow_subdomains: false,
+      detect_crawler_traps: None,
     };
 
     let result = _filter_links(data);
@@ -958,6 +1300,7 @@ mod tests {
       allow_backward_crawling: true,
       allow_external_content_links: false,
       allow_subdomains: false,
+      detect_crawler_traps: None,
     };
 
     let result = _filter_links(data);
@@ -985,6 +1328,7 @@ mod tests {
       allow_backward_crawling: true,
       allow_external_content_links: false,
       allow_subdomains: false,
+      detect_crawler_traps: None,
     };
 
     let result = _filter_links(data);
@@ -1015,6 +1359,7 @@ mod tests {
       allow_backward_crawling: true,
       allow_external_content_links: false,
       allow_subdomains: true,
+      detect_crawler_traps: None,
     };
 
     let result = _filter_links(data).unwrap();
@@ -1042,6 +1387,365 @@ mod tests {
     );
   }
 
+  /// Runs a breadth-first crawl over an in-memory link graph, seeded from an optional sitemap,
+  /// and feeds every page's links through `_filter_links` like the crawler does. Returns the
+  /// pages in visit order plus the denial reason recorded for each rejected link.
+  fn simulate_crawl(
+    graph: &[(&str, &[&str])],
+    start: &str,
+    sitemap: Option<&str>,
+    robots_txt: &str,
+    limit: usize,
+    max_depth: u32,
+  ) -> (Vec<String>, HashMap<String, String>) {
+    let graph: HashMap<&str, &[&str]> = graph.iter().copied().collect();
+    let mut visited = vec![start.to_string()];
+    let mut seen: HashSet<String> = HashSet::from([start.to_string()]);
+    let mut denials = HashMap::new();
+
+    // Sitemap URLs are discovered alongside the start page's own links
+    let seeds: Vec<String> = sitemap
+      .map(|xml| {
+        _process_sitemap(xml)
+          .unwrap()
+          .instructions
+          .into_iter()
+          .filter(|i| i.action == "process")
+          .flat_map(|i| i.urls)
+          .collect()
+      })
+      .unwrap_or_default();
+    let mut frontier = std::collections::VecDeque::from([(start.to_string(), seeds)]);
+
+    while let Some((page, seeded)) = frontier.pop_front() {
+      let remaining = limit.saturating_sub(visited.len());
+      if remaining == 0 {
+        break;
+      }
+
+      let links: Vec<String> = graph
+        .get(page.as_str())
+        .into_iter()
+        .flat_map(|links| links.iter().map(|l| l.to_string()))
+        .chain(seeded)
+        .filter(|l| !seen.contains(l))
+        .collect();
+
+      let result = _filter_links(FilterLinksCall {
+        links,
+        limit: Some(remaining as i64),
+        max_depth,
+        base_url: start.to_string(),
+        initial_url: start.to_string(),
+        regex_on_full_url: false,
+        excludes: vec![],
+        includes: vec![],
+        allow_backward_crawling: false,
+        ignore_robots_txt: false,
+        robots_txt: robots_txt.to_string(),
+        allow_external_content_links: false,
+        allow_subdomains: false,
+        detect_crawler_traps: None,
+      })
+      .unwrap();
+
+      denials.extend(result.denial_reasons);
+      for link in result.links {
+        if seen.insert(link.clone()) {
+          visited.push(link.clone());
+          frontier.push_back((link, Vec::new()));
+        }
+      }
+    }
+
+    (visited, denials)
+  }
+
+  #[test]
+  fn test_simulated_crawl_dedups_cycles_and_respects_limit() {
+    let graph: &[(&str, &[&str])] = &[
+      (
+        "https://example.com/",
+        &["https://example.com/a", "https://example.com/b"],
+      ),
+      (
+        "https://example.com/a",
+        &[
+          "https://example.com/",
+          "https://example.com/b",
+          "https://example.com/c",
+        ],
+      ),
+      (
+        "https://example.com/b",
+        &["https://example.com/a", "https://example.com/d"],
+      ),
+      ("https://example.com/c", &["https://example.com/e"]),
+    ];
+
+    let (visited, _) = simulate_crawl(graph, "https://example.com/", None, "", 100, 10);
+    assert_eq!(
+      visited,
+      vec![
+        "https://example.com/",
+        "https://example.com/a",
+        "https://example.com/b",
+        "https://example.com/c",
+        "https://example.com/d",
+        "https://example.com/e",
+      ]
+    );
+
+    let (visited, _) = simulate_crawl(graph, "https://example.com/", None, "", 4, 10);
+    assert_eq!(visited.len(), 4);
+    assert_eq!(visited[3], "https://example.com/c");
+  }
+
+  #[test]
+  fn test_simulated_crawl_depth_robots_and_sitemap() {
+    let graph: &[(&str, &[&str])] = &[
+      (
+        "https://example.com/",
+        &[
+          "https://example.com/docs",
+          "https://example.com/private/keys",
+          "https://twitter.com/example",
+        ],
+      ),
+      (
+        "https://example.com/docs",
+        &["https://example.com/docs/deep/page"],
+      ),
+    ];
+    let sitemap = r#"<?xml version="1.0" encoding="UTF-8"?>
+      <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <url><loc>https://example.com/from-sitemap</loc></url>
+        <url><loc>https://example.com/docs</loc></url>
+      </urlset>"#;
+
+    let (visited, denials) = simulate_crawl(
+      graph,
+      "https://example.com/",
+      Some(sitemap),
+      "User-agent: *\nDisallow: /private",
+      100,
+      2,
+    );
+
+    assert_eq!(
+      visited,
+      vec![
+        "https://example.com/",
+        "https://example.com/docs",
+        "https://example.com/from-sitemap",
+      ]
+    );
+    assert_eq!(denials["https://example.com/private/keys"], ROBOTS_TXT);
+    assert_eq!(denials["https://twitter.com/example"], SOCIAL_MEDIA);
+    assert_eq!(denials["https://example.com/docs/deep/page"], DEPTH_LIMIT);
+  }
+
+  #[test]
+  fn test_filter_links_glob_patterns() {
+    let data = FilterLinksCall {
+      links: vec![
+        "https://example.com/docs/intro".to_string(),
+        "https://example.com/docs/api/v1".to_string(),
+        "https://example.com/docs/drafts/wip".to_string(),
+        "https://example.com/blog/post".to_string(),
+      ],
+      limit: Some(10),
+      includes: vec!["glob:/docs/**".to_string()],
+      excludes: vec!["glob:/docs/drafts/*".to_string()],
+      ignore_robots_txt: true,
+      robots_txt: "".to_string(),
+      max_depth: 10,
+      base_url: "https://example.com".to_string(),
+      initial_url: "https://example.com".to_string(),
+      regex_on_full_url: false,
+      allow_backward_crawling: true,
+      allow_external_content_links: false,
+      allow_subdomains: false,
+      detect_crawler_traps: None,
+    };
+
+    let result = _filter_links(data).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/docs/intro",
+        "https://example.com/docs/api/v1",
+      ]
+    );
+    assert_eq!(
+      result.denial_reasons["https://example.com/docs/drafts/wip"],
+      EXCLUDE_PATTERN
+    );
+    assert_eq!(
+      result.denial_reasons["https://example.com/blog/post"],
+      INCLUDE_PATTERN
+    );
+  }
+
+  #[test]
+  fn test_filter_url_glob_excludes() {
+    let call = |href: &str| FilterUrlCall {
+      href: href.to_string(),
+      url: "https://example.com/".to_string(),
+      base_url: "https://example.com".to_string(),
+      excludes: vec!["glob:/docs/**/drafts/*".to_string()],
+      ignore_robots_txt: true,
+      robots_txt: "".to_string(),
+      allow_external_content_links: false,
+      allow_subdomains: false,
+    };
+
+    let denied = _filter_url(call("/docs/drafts/wip")).unwrap();
+    assert!(!denied.allowed);
+    assert_eq!(denied.denial_reason.as_deref(), Some(EXCLUDE_PATTERN));
+
+    let nested = _filter_url(call("/docs/v2/drafts/wip")).unwrap();
+    assert!(!nested.allowed);
+
+    let allowed = _filter_url(call("/docs/intro")).unwrap();
+    assert!(allowed.allowed);
+    assert_eq!(
+      allowed.url.as_deref(),
+      Some("https://example.com/docs/intro")
+    );
+  }
+
+  #[test]
+  fn test_filter_links_crawler_traps() {
+    let links = vec![
+      "https://example.com/docs/page".to_string(),
+      "https://example.com/a/b/a/b/a/b".to_string(),
+      "https://example.com/shop?PHPSESSID=abc123".to_string(),
+      "https://example.com/events/calendar?month=5&year=2031".to_string(),
+      "https://example.com/shop?color=red&size=m&brand=x&sort=asc&page=2&view=grid".to_string(),
+      "https://example.com/shop?page=2".to_string(),
+    ];
+    let call = |detect_crawler_traps| FilterLinksCall {
+      links: links.clone(),
+      limit: Some(10),
+      includes: vec![],
+      excludes: vec![],
+      ignore_robots_txt: true,
+      robots_txt: "".to_string(),
+      max_depth: 10,
+      base_url: "https://example.com".to_string(),
+      initial_url: "https://example.com".to_string(),
+      regex_on_full_url: false,
+      allow_backward_crawling: true,
+      allow_external_content_links: false,
+      allow_subdomains: false,
+      detect_crawler_traps,
+    };
+
+    let result = _filter_links(call(None)).unwrap();
+    assert_eq!(result.links.len(), links.len());
+
+    let result = _filter_links(call(Some(true))).unwrap();
+    assert_eq!(
+      result.links,
+      vec![
+        "https://example.com/docs/page",
+        "https://example.com/shop?page=2",
+      ]
+    );
+    assert_eq!(
+      result
+        .denial_reasons
+        .values()
+        .filter(|r| *r == CRAWLER_TRAP)
+        .count(),
+      4
+    );
+  }
+
+  #[test]
+  fn test_filter_links_limit_and_denial_counts() {
+    let call = |limit| FilterLinksCall {
+      links: vec![
+        "https://example.com/a".to_string(),
+        "https://other.com/x".to_string(),
+        "https://example.com/b".to_string(),
+        "https://example.com/c".to_string(),
+        "https://example.com/d".to_string(),
+      ],
+      limit,
+      includes: vec![],
+      excludes: vec![],
+      ignore_robots_txt: true,
+      robots_txt: "".to_string(),
+      max_depth: 10,
+      base_url: "https://example.com".to_string(),
+      initial_url: "https://example.com".to_string(),
+      regex_on_full_url: false,
+      allow_backward_crawling: true,
+      allow_external_content_links: false,
+      allow_subdomains: false,
+      detect_crawler_traps: None,
+    };
+
+    let result = _filter_links(call(Some(2))).unwrap();
+    assert_eq!(
+      result.links,
+      vec!["https://example.com/a", "https://example.com/b"]
+    );
+    assert_eq!(result.denial_reasons["https://other.com/x"], EXTERNAL_LINK);
+    assert_eq!(result.denial_reasons["https://example.com/c"], LIMIT);
+    assert_eq!(result.denial_reasons["https://example.com/d"], LIMIT);
+    assert_eq!(
+      result.denial_counts,
+      HashMap::from([(EXTERNAL_LINK.to_string(), 1), (LIMIT.to_string(), 2)])
+    );
+
+    let result = _filter_links(call(Some(0))).unwrap();
+    assert!(result.links.is_empty());
+    assert_eq!(result.denial_counts[LIMIT], 5);
+  }
+
+  #[test]
+  fn test_compile_pattern() {
+    let single = compile_pattern("glob:/docs/*").unwrap();
+    assert!(single.is_match("/docs/intro"));
+    assert!(!single.is_match("/docs/api/v1"));
+
+    let escaped = compile_pattern("glob:/file?.html").unwrap();
+    assert!(escaped.is_match("/file1.html"));
+    assert!(!escaped.is_match("/file1xhtml"));
+
+    let nested = compile_pattern("glob:/a/**/b").unwrap();
+    assert!(nested.is_match("/a/b"));
+    assert!(nested.is_match("/a/x/b"));
+    assert!(nested.is_match("/a/x/y/b"));
+    assert!(!nested.is_match("/ab"));
+    assert!(!nested.is_match("/a/xb"));
+
+    let trailing = compile_pattern("glob:/docs/**").unwrap();
+    assert!(trailing.is_match("/docs/"));
+    assert!(trailing.is_match("/docs/api/v1"));
+
+    let regex = compile_pattern("^/blog/.*$").unwrap();
+    assert!(regex.is_match("/blog/post"));
+
+    assert!(compile_pattern("(").is_err());
+  }
+
+  #[test]
+  fn test_validate_patterns() {
+    let errors = _validate_patterns(&[
+      "^/blog/.*$".to_string(),
+      "glob:/docs/**".to_string(),
+      "/unclosed(".to_string(),
+    ]);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].pattern, "/unclosed(");
+    assert!(!errors[0].error.is_empty());
+  }
+
   #[test]
   fn test_is_file() {
     assert!(is_file("test.png"));
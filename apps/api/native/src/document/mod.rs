@@ -47,4 +47,9 @@ impl DocumentConverter {
     let html = self.html_renderer.render(&document);
     Ok(html)
   }
+
+  #[napi]
+  pub fn detect_document_type(&self, data: &[u8]) -> Option<DocumentType> {
+    DocumentType::detect(data)
+  }
 }
@@ -4,7 +4,14 @@ use super::odt::OdtProvider;
 use super::rtf::RtfProvider;
 use super::DocumentProvider;
 use super::xlsx::XlsxProvider;
+use cfb::CompoundFile;
 use napi_derive::napi;
+use std::io::{Cursor, Read};
+use zip::read::ZipArchive;
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const CFB_MAGIC: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const ODT_MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
 
 #[napi]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,6 +23,49 @@ pub enum DocumentType {
   Xlsx,
 }
 
+impl DocumentType {
+  /// Sniffs the document type from the file contents, for servers that send a missing, generic
+  /// or wrong content type. Legacy OLE2 files are told apart by their Word or Excel stream.
+  pub fn detect(data: &[u8]) -> Option<Self> {
+    let text = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    if text.trim_ascii_start().starts_with(b"{\\rtf") {
+      return Some(DocumentType::Rtf);
+    }
+
+    if data.starts_with(CFB_MAGIC) {
+      let cfb = CompoundFile::open(Cursor::new(data)).ok()?;
+      if cfb.exists("/WordDocument") {
+        return Some(DocumentType::Doc);
+      }
+      if cfb.exists("/Workbook") || cfb.exists("/Book") {
+        return Some(DocumentType::Xlsx);
+      }
+      return None;
+    }
+
+    if data.starts_with(ZIP_MAGIC) {
+      let mut zip = ZipArchive::new(Cursor::new(data)).ok()?;
+
+      let mut mimetype = String::new();
+      if let Ok(mut file) = zip.by_name("mimetype") {
+        let _ = file.read_to_string(&mut mimetype);
+      }
+      if mimetype.trim() == ODT_MIMETYPE {
+        return Some(DocumentType::Odt);
+      }
+
+      if zip.by_name("word/document.xml").is_ok() {
+        return Some(DocumentType::Docx);
+      }
+      if zip.by_name("xl/workbook.xml").is_ok() {
+        return Some(DocumentType::Xlsx);
+      }
+    }
+
+    None
+  }
+}
+
 pub struct ProviderFactory {
   doc_provider: DocProvider,
   docx_provider: DocxProvider,
@@ -45,3 +95,92 @@ impl ProviderFactory {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+  use zip::write::SimpleFileOptions;
+  use zip::ZipWriter;
+
+  fn zip_with(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, contents) in entries {
+      zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+      zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+  }
+
+  fn cfb_with(stream: &str) -> Vec<u8> {
+    let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    cfb
+      .create_stream(stream)
+      .unwrap()
+      .write_all(b"data")
+      .unwrap();
+    cfb.into_inner().into_inner()
+  }
+
+  #[test]
+  fn test_detect_docx() {
+    let data = zip_with(&[
+      ("[Content_Types].xml", "<Types/>"),
+      ("word/document.xml", "<w:document/>"),
+    ]);
+    assert_eq!(DocumentType::detect(&data), Some(DocumentType::Docx));
+  }
+
+  #[test]
+  fn test_detect_xlsx() {
+    let data = zip_with(&[
+      ("[Content_Types].xml", "<Types/>"),
+      ("xl/workbook.xml", "<workbook/>"),
+    ]);
+    assert_eq!(DocumentType::detect(&data), Some(DocumentType::Xlsx));
+  }
+
+  #[test]
+  fn test_detect_odt() {
+    let data = zip_with(&[("mimetype", ODT_MIMETYPE), ("content.xml", "<office/>")]);
+    assert_eq!(DocumentType::detect(&data), Some(DocumentType::Odt));
+  }
+
+  #[test]
+  fn test_detect_rtf() {
+    assert_eq!(
+      DocumentType::detect(b"{\\rtf1\\ansi Hello}"),
+      Some(DocumentType::Rtf)
+    );
+    assert_eq!(
+      DocumentType::detect(b"\xEF\xBB\xBF\n  {\\rtf1 Hello}"),
+      Some(DocumentType::Rtf)
+    );
+  }
+
+  #[test]
+  fn test_detect_legacy_ole2() {
+    assert_eq!(
+      DocumentType::detect(&cfb_with("/WordDocument")),
+      Some(DocumentType::Doc)
+    );
+    assert_eq!(
+      DocumentType::detect(&cfb_with("/Workbook")),
+      Some(DocumentType::Xlsx)
+    );
+    assert_eq!(DocumentType::detect(&cfb_with("/Other")), None);
+  }
+
+  #[test]
+  fn test_detect_unknown() {
+    let data = zip_with(&[
+      ("readme.txt", "just a zip"),
+      ("mimetype", "application/zip"),
+    ]);
+    assert_eq!(DocumentType::detect(&data), None);
+
+    assert_eq!(DocumentType::detect(b"PK\x03\x04 truncated"), None);
+    assert_eq!(DocumentType::detect(b"%PDF-1.7"), None);
+    assert_eq!(DocumentType::detect(b""), None);
+  }
+}
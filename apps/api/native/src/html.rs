@@ -16,6 +16,8 @@ static URL_REGEX: LazyLock<Regex> =
 
 use crate::utils::to_napi_err;
 
+mod readability;
+
 fn _extract_base_href_from_document(
   document: &NodeRef,
   url: &Url,
@@ -58,6 +60,249 @@ pub async fn extract_base_href(html: String, url: String) -> napi::Result<String
   res.map_err(to_napi_err)
 }
 
+static META_REFRESH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"(?i)^\s*(\d+(?:\.\d+)?)?\s*[;,]?\s*(?:url\s*=\s*)?['"]?([^'"]*)['"]?\s*$"#)
+    .expect("META_REFRESH_REGEX is a valid static regex pattern")
+});
+
+static JS_REDIRECT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r#"(?:^|[^\w$.])(?:(?:window|document|top|self)\.)?location(?:(?:\.href)?\s*=\s*['"]([^'"]+)['"]|\.(?:replace|assign)\(\s*['"]([^'"]+)['"]\s*\))"#,
+  )
+  .expect("JS_REDIRECT_REGEX is a valid static regex pattern")
+});
+
+/// Meta refreshes with a longer delay are treated as regular content, not redirects.
+const MAX_META_REFRESH_DELAY: f64 = 10.0;
+/// JS redirects are only trusted on stub pages with at most this much visible text.
+const MAX_JS_REDIRECT_PAGE_TEXT: usize = 500;
+
+/// Number of characters of body text, not counting script, style and noscript contents.
+fn visible_text_len(document: &NodeRef) -> usize {
+  document
+    .select_first("body")
+    .map(|body| {
+      body
+        .as_node()
+        .descendants()
+        .filter(|node| {
+          !node.ancestors().any(|ancestor| {
+            ancestor
+              .as_element()
+              .is_some_and(|el| matches!(el.name.local.as_ref(), "script" | "style" | "noscript"))
+          })
+        })
+        .filter_map(|node| {
+          node
+            .as_text()
+            .map(|text| text.borrow().trim().chars().count())
+        })
+        .sum::<usize>()
+    })
+    .unwrap_or(0)
+}
+
+#[derive(Serialize)]
+#[napi(object)]
+pub struct ClientRedirect {
+  pub url: String,
+  /// Either `meta_refresh` or `javascript`.
+  pub kind: String,
+}
+
+fn _detect_client_redirect(
+  html: &str,
+  url: &str,
+) -> Result<Option<ClientRedirect>, Box<dyn std::error::Error + Send + Sync>> {
+  let document = parse_html().one(html);
+  let url = Url::parse(url)?;
+  let base = Url::parse(&_extract_base_href_from_document(&document, &url)?)?;
+
+  for meta in document
+    .select("meta[http-equiv]")
+    .map_err(|_| "Failed to select meta http-equiv")?
+  {
+    let attributes = meta.attributes.borrow();
+    if !attributes
+      .get("http-equiv")
+      .is_some_and(|x| x.trim().eq_ignore_ascii_case("refresh"))
+    {
+      continue;
+    }
+
+    let Some(captures) = attributes
+      .get("content")
+      .and_then(|content| META_REFRESH_REGEX.captures(content))
+    else {
+      continue;
+    };
+
+    let delay = captures
+      .get(1)
+      .and_then(|d| d.as_str().parse::<f64>().ok())
+      .unwrap_or(0.0);
+    let target = captures.get(2).map_or("", |t| t.as_str().trim());
+    if delay > MAX_META_REFRESH_DELAY || target.is_empty() {
+      continue;
+    }
+
+    if let Ok(target) = base.join(target) {
+      if matches!(target.scheme(), "http" | "https") && target != url {
+        return Ok(Some(ClientRedirect {
+          url: target.to_string(),
+          kind: "meta_refresh".to_string(),
+        }));
+      }
+    }
+  }
+
+  if visible_text_len(&document) > MAX_JS_REDIRECT_PAGE_TEXT {
+    return Ok(None);
+  }
+
+  for script in document
+    .select("script:not([src])")
+    .map_err(|_| "Failed to select scripts")?
+  {
+    let source = script.text_contents();
+    let Some(captures) = JS_REDIRECT_REGEX.captures(&source) else {
+      continue;
+    };
+
+    let target = captures
+      .get(1)
+      .or_else(|| captures.get(2))
+      .map_or("", |t| t.as_str().trim());
+    if let Ok(target) = base.join(target) {
+      if matches!(target.scheme(), "http" | "https") && target != url {
+        return Ok(Some(ClientRedirect {
+          url: target.to_string(),
+          kind: "javascript".to_string(),
+        }));
+      }
+    }
+  }
+
+  Ok(None)
+}
+
+/// Detect an immediate client-side redirect (meta refresh or JS `location` assignment) on a stub
+/// page, returning the absolute target URL.
+#[napi]
+pub async fn detect_client_redirect(
+  html: String,
+  url: String,
+) -> napi::Result<Option<ClientRedirect>> {
+  let res = task::spawn_blocking(move || _detect_client_redirect(&html, &url))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("detect_client_redirect join error: {e}"),
+      )
+    })?;
+
+  res.map_err(to_napi_err)
+}
+
+/// Markup fingerprints of interstitial anti-bot pages, paired with the provider they belong to.
+const CHALLENGE_MARKERS: &[(&str, &str)] = &[
+  ("cloudflare", "cf-browser-verification"),
+  ("cloudflare", "window._cf_chl_opt"),
+  ("cloudflare", "cf-error-details"),
+  ("datadome", "captcha-delivery.com"),
+  ("perimeterx", "px-captcha"),
+  ("imperva", "_Incapsula_Resource"),
+  ("sucuri", "sucuri_cloudproxy_js"),
+  ("akamai", "errors.edgesuite.net"),
+];
+
+/// Page titles used by challenge pages, matched case-insensitively against the whole title
+/// (ignoring trailing dots and ellipses).
+const CHALLENGE_TITLES: &[(&str, &str)] = &[
+  ("cloudflare", "just a moment"),
+  ("cloudflare", "attention required! | cloudflare"),
+  ("imperva", "pardon our interruption"),
+  ("generic", "access denied"),
+  ("generic", "are you a robot?"),
+  ("generic", "please verify you are a human"),
+  ("generic", "security check"),
+];
+
+/// A generic challenge title only counts when the page also carries one of these, so a short
+/// page that merely happens to be titled "Access Denied" is not reported.
+const GENERIC_CHALLENGE_SIGNALS: &[&str] = &[
+  "captcha",
+  "challenge",
+  "turnstile",
+  "verify you are human",
+  "enable javascript and cookies",
+];
+
+/// Pages with more visible text than this are treated as real content even if they carry a marker.
+const MAX_CHALLENGE_PAGE_TEXT: usize = 1000;
+
+fn _detect_challenge_page(html: &str) -> Option<String> {
+  // Most pages carry neither a marker nor a challenge title anywhere in the markup, so rule them
+  // out with a plain substring scan before paying for a DOM parse.
+  let html_lower = html.to_lowercase();
+  let might_be_challenge = CHALLENGE_MARKERS
+    .iter()
+    .any(|(_, marker)| html.contains(marker))
+    || CHALLENGE_TITLES
+      .iter()
+      .any(|(_, challenge_title)| html_lower.contains(challenge_title));
+  if !might_be_challenge {
+    return None;
+  }
+
+  let document = parse_html().one(html);
+
+  if visible_text_len(&document) > MAX_CHALLENGE_PAGE_TEXT {
+    return None;
+  }
+
+  if let Some((provider, _)) = CHALLENGE_MARKERS
+    .iter()
+    .find(|(_, marker)| html.contains(marker))
+  {
+    return Some(provider.to_string());
+  }
+
+  let title = document
+    .select_first("title")
+    .map(|title| title.text_contents().trim().to_lowercase())
+    .ok()?;
+  let title = title.trim_end_matches(['.', '\u{2026}']).trim_end();
+  let (provider, _) = CHALLENGE_TITLES
+    .iter()
+    .find(|(_, challenge_title)| title == *challenge_title)?;
+
+  if *provider == "generic"
+    && !GENERIC_CHALLENGE_SIGNALS
+      .iter()
+      .any(|signal| html_lower.contains(signal))
+  {
+    return None;
+  }
+
+  Some(provider.to_string())
+}
+
+/// Detect an anti-bot challenge or block interstitial, returning the provider that served it
+/// (`generic` when only the page title gives it away).
+#[napi]
+pub async fn detect_challenge_page(html: String) -> napi::Result<Option<String>> {
+  task::spawn_blocking(move || _detect_challenge_page(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("detect_challenge_page join error: {e}"),
+      )
+    })
+}
+
 /// Extract all links from HTML document.
 #[napi]
 pub async fn extract_links(html: Option<String>) -> napi::Result<Vec<String>> {
@@ -142,8 +387,15 @@ macro_rules! insert_meta_property {
 
 fn _extract_metadata(
   html: &str,
+  url: Option<&str>,
 ) -> Result<HashMap<String, Value>, Box<dyn std::error::Error + Send + Sync>> {
   let document = parse_html().one(html);
+  let base = match url.map(Url::parse) {
+    Some(Ok(url)) => Some(Url::parse(&_extract_base_href_from_document(
+      &document, &url,
+    )?)?),
+    _ => None,
+  };
   let mut out = HashMap::<String, Value>::new();
 
   let head_node = document
@@ -179,6 +431,25 @@ fn _extract_metadata(
     out.insert("favicon".to_string(), Value::String(favicon_link));
   }
 
+  if let Some(canonical) = search_root
+    .select("link[rel~=\"canonical\" i][href]")
+    .map_err(|_| "Failed to select canonical")?
+    .next()
+    .and_then(|x| {
+      x.attributes
+        .borrow()
+        .get("href")
+        .map(|x| x.trim().to_string())
+    })
+    .filter(|x| !x.is_empty())
+  {
+    let canonical = match base.as_ref().map(|base| base.join(&canonical)) {
+      Some(Ok(resolved)) => resolved.to_string(),
+      _ => canonical,
+    };
+    out.insert("canonicalUrl".to_string(), Value::String(canonical));
+  }
+
   if let Some(lang) = document
     .select("html[lang]")
     .map_err(|_| "Failed to select lang")?
@@ -311,16 +582,20 @@ fn _extract_metadata(
   Ok(out)
 }
 
-/// Extract metadata from HTML document.
+/// Extract metadata from HTML document. When the page URL is given, a relative canonical link is
+/// resolved against it (honouring `<base href>`).
 #[napi]
-pub async fn extract_metadata(html: Option<String>) -> napi::Result<HashMap<String, Value>> {
+pub async fn extract_metadata(
+  html: Option<String>,
+  url: Option<String>,
+) -> napi::Result<HashMap<String, Value>> {
   task::spawn_blocking(move || {
     let html = match html {
       Some(h) => h,
       None => return Ok(HashMap::new()),
     };
 
-    _extract_metadata(&html).map_err(to_napi_err)
+    _extract_metadata(&html, url.as_deref()).map_err(to_napi_err)
   })
   .await
   .map_err(|e| {
@@ -644,6 +919,19 @@ pub async fn transform_html(opts: TransformHtmlOptions) -> napi::Result<String>
   res.map_err(to_napi_err)
 }
 
+/// Extract the main article content from HTML using readability-style scoring.
+#[napi]
+pub async fn extract_main_content(html: String) -> napi::Result<String> {
+  task::spawn_blocking(move || readability::_extract_main_content(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_main_content join error: {e}"),
+      )
+    })
+}
+
 fn _get_inner_json(html: &str) -> Result<String, ()> {
   Ok(parse_html().one(html).select_first("body")?.text_contents())
 }
@@ -663,6 +951,100 @@ pub async fn get_inner_json(html: String) -> napi::Result<String> {
   res.map_err(|_| to_napi_err("Failed to get inner JSON"))
 }
 
+#[derive(Serialize)]
+#[napi(object)]
+pub struct CodeBlock {
+  pub language: Option<String>,
+  pub content: String,
+  /// Text of the closest heading preceding the block, if any.
+  pub heading: Option<String>,
+}
+
+fn code_block_language(node: &NodeRef) -> Option<String> {
+  let element = node.as_element()?;
+  let attributes = element.attributes.borrow();
+
+  attributes
+    .get("class")
+    .and_then(|class| {
+      class.split_whitespace().find_map(|c| {
+        c.strip_prefix("language-")
+          .or_else(|| c.strip_prefix("lang-"))
+          .filter(|lang| !lang.is_empty())
+      })
+    })
+    .or_else(|| attributes.get("data-language"))
+    .or_else(|| attributes.get("data-lang"))
+    .map(|lang| lang.to_lowercase())
+}
+
+fn _extract_code_blocks(html: &str) -> Vec<CodeBlock> {
+  let document = parse_html().one(html);
+  let mut blocks = Vec::new();
+  let mut heading: Option<String> = None;
+
+  for edge in document.traverse() {
+    let NodeEdge::Start(node) = edge else {
+      continue;
+    };
+    let Some(element) = node.as_element() else {
+      continue;
+    };
+
+    match element.name.local.as_ref() {
+      "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+        let text = node.text_contents().trim().to_string();
+        if !text.is_empty() {
+          heading = Some(text);
+        }
+      }
+      "pre" => {
+        // A nested <pre> is already part of its outer block's content
+        if node.ancestors().any(|ancestor| {
+          ancestor
+            .as_element()
+            .is_some_and(|a| &*a.name.local == "pre")
+        }) {
+          continue;
+        }
+
+        let content = node.text_contents();
+        if content.trim().is_empty() {
+          continue;
+        }
+
+        let code = node.select_first("code").ok();
+        let language = code
+          .as_ref()
+          .and_then(|code| code_block_language(code.as_node()))
+          .or_else(|| code_block_language(&node));
+
+        blocks.push(CodeBlock {
+          language,
+          content: content.trim_end_matches('\n').to_string(),
+          heading: heading.clone(),
+        });
+      }
+      _ => {}
+    }
+  }
+
+  blocks
+}
+
+/// Extract code blocks (`<pre>` elements) with their language and the heading they appear under.
+#[napi]
+pub async fn extract_code_blocks(html: String) -> napi::Result<Vec<CodeBlock>> {
+  task::spawn_blocking(move || _extract_code_blocks(&html))
+    .await
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("extract_code_blocks join error: {e}"),
+      )
+    })
+}
+
 #[derive(Deserialize, Serialize)]
 #[napi(object)]
 pub struct AttributeSelector {
@@ -930,9 +1312,292 @@ pub async fn extract_images(html: String, base_url: String) -> napi::Result<Vec<
   res.map_err(to_napi_err)
 }
 
-/// Process multi-line links in markdown.
+#[derive(Deserialize)]
+#[napi(object)]
+pub struct MarkdownReplaceRule {
+  /// Regular expression matched against the whole markdown document.
+  pub pattern: String,
+  /// Replacement text, supporting `$1`-style capture references. Matches are removed when unset.
+  pub replacement: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[napi(object)]
+pub struct MarkdownPostProcessOptions {
+  pub replace: Option<Vec<MarkdownReplaceRule>>,
+  /// Heading texts (case-insensitive) whose sections are removed, up to the next heading of the
+  /// same or a higher level.
+  pub strip_sections: Option<Vec<String>>,
+  pub collapse_blank_lines: Option<bool>,
+  /// Number of levels to demote every heading by, capped at `######`.
+  pub heading_offset: Option<u32>,
+}
+
+/// Leading spaces before a block-level marker, or `None` when the line is indented enough (four
+/// columns, as in CommonMark) to be an indented code block instead.
+fn block_indent(line: &str) -> Option<usize> {
+  let indent = line.len() - line.trim_start_matches(' ').len();
+  (indent <= 3 && !line[indent..].starts_with('\t')).then_some(indent)
+}
+
+fn heading_level(line: &str) -> Option<(usize, &str)> {
+  let trimmed = &line[block_indent(line)?..];
+  let level = trimmed.chars().take_while(|c| *c == '#').count();
+  if level == 0 || level > 6 {
+    return None;
+  }
+
+  let rest = &trimmed[level..];
+  if !rest.trim_end_matches(['\r', '\n']).is_empty() && !rest.starts_with([' ', '\t']) {
+    return None;
+  }
+
+  Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn setext_underline_level(line: &str) -> Option<usize> {
+  let marker = line[block_indent(line)?..].trim_end();
+  if marker.is_empty() {
+    None
+  } else if marker.chars().all(|c| c == '=') {
+    Some(1)
+  } else if marker.chars().all(|c| c == '-') {
+    Some(2)
+  } else {
+    None
+  }
+}
+
+static LIST_ITEM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(?:[-+*]|\d{1,9}[.)])(?:[ \t]|$)")
+    .expect("LIST_ITEM_REGEX is a valid static regex pattern")
+});
+
+/// Whether `line` could be the text of a setext heading, i.e. a plain paragraph line.
+fn is_paragraph_line(line: &str) -> bool {
+  let Some(indent) = block_indent(line) else {
+    return false;
+  };
+  let text = line[indent..].trim_end();
+  !text.is_empty()
+    && !text.starts_with('>')
+    && !LIST_ITEM_REGEX.is_match(text)
+    && heading_level(line).is_none()
+    && setext_underline_level(line).is_none()
+}
+
+/// Tracks fenced code blocks while scanning markdown line by line.
+#[derive(Default)]
+struct FenceTracker {
+  open: Option<(char, usize)>,
+}
+
+impl FenceTracker {
+  /// Returns whether `line` is a fence or sits inside a fenced code block.
+  fn in_code(&mut self, line: &str) -> bool {
+    let fence = block_indent(line).and_then(|indent| {
+      let trimmed = &line[indent..];
+      let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+      let len = trimmed.chars().take_while(|c| *c == marker).count();
+      (len >= 3).then_some((marker, len, trimmed[len..].trim().is_empty()))
+    });
+
+    match (self.open, fence) {
+      (Some((open, open_len)), Some((marker, len, bare))) => {
+        if marker == open && len >= open_len && bare {
+          self.open = None;
+        }
+        true
+      }
+      (Some(_), None) => true,
+      (None, Some((marker, len, _))) => {
+        self.open = Some((marker, len));
+        true
+      }
+      (None, None) => false,
+    }
+  }
+}
+
+/// A markdown line, or a setext heading together with its underline.
+struct MarkdownBlock<'a> {
+  text: &'a str,
+  /// Level and text of the heading, when the block is one outside of fenced code.
+  heading: Option<(usize, &'a str)>,
+  setext: bool,
+}
+
+fn markdown_blocks(markdown: &str) -> Vec<MarkdownBlock<'_>> {
+  let lines: Vec<&str> = markdown.split_inclusive('\n').collect();
+  let mut blocks = Vec::with_capacity(lines.len());
+  let mut fences = FenceTracker::default();
+  let mut paragraph_start = true;
+  let mut pos = 0;
+  let mut i = 0;
+
+  while i < lines.len() {
+    let line = lines[i];
+    let start = pos;
+    pos += line.len();
+    i += 1;
+
+    if fences.in_code(line) {
+      paragraph_start = true;
+      blocks.push(MarkdownBlock {
+        text: line,
+        heading: None,
+        setext: false,
+      });
+      continue;
+    }
+
+    if let Some(heading) = heading_level(line) {
+      paragraph_start = true;
+      blocks.push(MarkdownBlock {
+        text: line,
+        heading: Some(heading),
+        setext: false,
+      });
+      continue;
+    }
+
+    if paragraph_start && is_paragraph_line(line) {
+      if let Some(level) = lines.get(i).and_then(|next| setext_underline_level(next)) {
+        pos += lines[i].len();
+        i += 1;
+        blocks.push(MarkdownBlock {
+          text: &markdown[start..pos],
+          heading: Some((level, line.trim())),
+          setext: true,
+        });
+        continue;
+      }
+    }
+
+    paragraph_start = line.trim().is_empty();
+    blocks.push(MarkdownBlock {
+      text: line,
+      heading: None,
+      setext: false,
+    });
+  }
+
+  blocks
+}
+
+fn strip_sections(markdown: &str, headings: &[String]) -> String {
+  let mut out = String::with_capacity(markdown.len());
+  let mut stripping: Option<usize> = None;
+
+  for block in markdown_blocks(markdown) {
+    if let Some((level, text)) = block.heading {
+      if stripping.is_some_and(|stripped_level| level <= stripped_level) {
+        stripping = None;
+      }
+      if stripping.is_none() && headings.iter().any(|h| h.trim().eq_ignore_ascii_case(text)) {
+        stripping = Some(level);
+      }
+    }
+
+    if stripping.is_none() {
+      out.push_str(block.text);
+    }
+  }
+
+  out
+}
+
+fn offset_headings(markdown: &str, offset: usize) -> String {
+  let mut out = String::with_capacity(markdown.len() + offset * 8);
+
+  for block in markdown_blocks(markdown) {
+    let Some((level, text)) = block.heading else {
+      out.push_str(block.text);
+      continue;
+    };
+
+    let hashes = "#".repeat((level + offset).min(6));
+    if block.setext {
+      // Setext headings only have two levels, so demoted ones are rewritten as ATX headings
+      out.push_str(&hashes);
+      out.push(' ');
+      out.push_str(text);
+      if block.text.ends_with('\n') {
+        out.push('\n');
+      }
+    } else {
+      let indent = block.text.len() - block.text.trim_start_matches(' ').len();
+      out.push_str(&block.text[..indent]);
+      out.push_str(&hashes);
+      out.push_str(&block.text[indent + level..]);
+    }
+  }
+
+  out
+}
+
+/// Collapses runs of blank lines into one, leaving fenced code untouched.
+fn collapse_blank_lines(markdown: &str) -> String {
+  let mut out = String::with_capacity(markdown.len());
+  let mut fences = FenceTracker::default();
+  let mut previous_blank = false;
+
+  for line in markdown.split_inclusive('\n') {
+    if fences.in_code(line) {
+      previous_blank = false;
+      out.push_str(line);
+      continue;
+    }
+
+    let blank = line.trim().is_empty();
+    if blank && previous_blank {
+      continue;
+    }
+
+    previous_blank = blank;
+    out.push_str(line);
+  }
+
+  out
+}
+
+fn _apply_markdown_rules(
+  markdown: String,
+  options: &MarkdownPostProcessOptions,
+) -> Result<String, String> {
+  let mut markdown = markdown;
+
+  for rule in options.replace.iter().flatten() {
+    let regex =
+      Regex::new(&rule.pattern).map_err(|e| format!("Invalid pattern {:?}: {e}", rule.pattern))?;
+    markdown = regex
+      .replace_all(&markdown, rule.replacement.as_deref().unwrap_or(""))
+      .into_owned();
+  }
+
+  if let Some(headings) = options.strip_sections.as_deref().filter(|h| !h.is_empty()) {
+    markdown = strip_sections(&markdown, headings);
+  }
+
+  if let Some(offset) = options.heading_offset.filter(|o| *o > 0) {
+    markdown = offset_headings(&markdown, offset as usize);
+  }
+
+  if options.collapse_blank_lines == Some(true) {
+    markdown = collapse_blank_lines(&markdown);
+  }
+
+  Ok(markdown)
+}
+
+/// Process multi-line links in markdown, then apply the optional user-configured rules
+/// (regex replacements, section stripping by heading, heading offset, blank line collapsing) in
+/// that order.
 #[napi]
-pub async fn post_process_markdown(markdown: String) -> napi::Result<String> {
+pub async fn post_process_markdown(
+  markdown: String,
+  options: Option<MarkdownPostProcessOptions>,
+) -> napi::Result<String> {
   let res = task::spawn_blocking(move || {
     let mut link_open_count = 0usize;
     let mut out = String::with_capacity(markdown.len());
@@ -957,7 +1622,11 @@ pub async fn post_process_markdown(markdown: String) -> napi::Result<String> {
       }
     }
 
-    remove_skip_to_content_links(&out)
+    let out = remove_skip_to_content_links(&out);
+    match options {
+      Some(options) => _apply_markdown_rules(out, &options),
+      None => Ok(out),
+    }
   })
   .await
   .map_err(|e| {
@@ -967,7 +1636,7 @@ pub async fn post_process_markdown(markdown: String) -> napi::Result<String> {
     )
   })?;
 
-  Ok(res)
+  res.map_err(to_napi_err)
 }
 
 fn remove_skip_to_content_links(input: &str) -> String {
@@ -1012,3 +1681,174 @@ fn remove_skip_to_content_links(input: &str) -> String {
 
   out
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn apply(markdown: &str, options: MarkdownPostProcessOptions) -> String {
+    _apply_markdown_rules(markdown.to_string(), &options).unwrap()
+  }
+
+  #[test]
+  fn test_extract_code_blocks() {
+    let blocks = _extract_code_blocks(
+      r#"<h2>Install</h2><pre><code class="language-sh">npm i firecrawl
+</code></pre><p>text</p><pre>   </pre><h3>Use</h3><pre class="lang-js">run()</pre>"#,
+    );
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0].language.as_deref(), Some("sh"));
+    assert_eq!(blocks[0].content, "npm i firecrawl");
+    assert_eq!(blocks[0].heading.as_deref(), Some("Install"));
+    assert_eq!(blocks[1].language.as_deref(), Some("js"));
+    assert_eq!(blocks[1].content, "run()");
+    assert_eq!(blocks[1].heading.as_deref(), Some("Use"));
+  }
+
+  #[test]
+  fn test_extract_code_blocks_skips_nested_pre() {
+    let blocks = _extract_code_blocks("<pre>outer <pre>inner</pre> tail</pre><pre>next</pre>");
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0].content, "outer inner tail");
+    assert_eq!(blocks[1].content, "next");
+  }
+
+  #[test]
+  fn test_heading_level() {
+    assert_eq!(heading_level("# Title\n"), Some((1, "Title")));
+    assert_eq!(heading_level("   ### Title ##\n"), Some((3, "Title")));
+    assert_eq!(heading_level("    # comment\n"), None);
+    assert_eq!(heading_level("\t# comment\n"), None);
+    assert_eq!(heading_level("#hashtag\n"), None);
+    assert_eq!(heading_level("####### seven\n"), None);
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_strip_sections() {
+    let markdown = "# Intro\nkeep\n## Related\ndrop\n### Deeper\ndrop too\n## Next\nkeep\n";
+    let options = MarkdownPostProcessOptions {
+      strip_sections: Some(vec!["related".to_string()]),
+      ..Default::default()
+    };
+    assert_eq!(apply(markdown, options), "# Intro\nkeep\n## Next\nkeep\n");
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_strip_sections_ignores_code() {
+    let markdown = "## Usage\n```sh\n# Related\n```\n    # Related\n## Related\ndrop\n";
+    let options = MarkdownPostProcessOptions {
+      strip_sections: Some(vec!["Related".to_string()]),
+      ..Default::default()
+    };
+    assert_eq!(
+      apply(markdown, options),
+      "## Usage\n```sh\n# Related\n```\n    # Related\n"
+    );
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_strip_setext_sections() {
+    let markdown = "Intro\n=====\nkeep\n\nRelated\n-------\ndrop\n\nNext\n----\nkeep\n";
+    let options = MarkdownPostProcessOptions {
+      strip_sections: Some(vec!["Related".to_string()]),
+      ..Default::default()
+    };
+    assert_eq!(
+      apply(markdown, options),
+      "Intro\n=====\nkeep\n\nNext\n----\nkeep\n"
+    );
+
+    // A list item followed by `---` is a list and a thematic break, not a heading
+    let markdown = "- Related\n---\nkeep\n";
+    let options = MarkdownPostProcessOptions {
+      strip_sections: Some(vec!["Related".to_string()]),
+      ..Default::default()
+    };
+    assert_eq!(apply(markdown, options), markdown);
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_heading_offset() {
+    let markdown = "# Title\n  ## Sub\n##### Deep\n```\n# not a heading\n```\n    # code\n";
+    let options = MarkdownPostProcessOptions {
+      heading_offset: Some(2),
+      ..Default::default()
+    };
+    assert_eq!(
+      apply(markdown, options),
+      "### Title\n  #### Sub\n###### Deep\n```\n# not a heading\n```\n    # code\n"
+    );
+
+    let options = MarkdownPostProcessOptions {
+      heading_offset: Some(1),
+      ..Default::default()
+    };
+    assert_eq!(
+      apply("Title\n=====\n\ntext\n", options),
+      "## Title\n\ntext\n"
+    );
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_collapse_blank_lines() {
+    let markdown = "a\n\n\n  \nb\n```\ncode\n\n\n\nmore\n```\n\n\nc\n";
+    let options = MarkdownPostProcessOptions {
+      collapse_blank_lines: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(
+      apply(markdown, options),
+      "a\n\nb\n```\ncode\n\n\n\nmore\n```\n\nc\n"
+    );
+
+    let options = MarkdownPostProcessOptions {
+      collapse_blank_lines: Some(false),
+      ..Default::default()
+    };
+    assert_eq!(apply(markdown, options), markdown);
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_in_order() {
+    let markdown = "# Title\n\n\n[edit]\n\n## Ads\nbuy\n\n\n## Body\ntext\n";
+    let options = MarkdownPostProcessOptions {
+      replace: Some(vec![MarkdownReplaceRule {
+        pattern: r"\[edit\]\n".to_string(),
+        replacement: None,
+      }]),
+      strip_sections: Some(vec!["Ads".to_string()]),
+      collapse_blank_lines: Some(true),
+      heading_offset: Some(1),
+    };
+    assert_eq!(apply(markdown, options), "## Title\n\n### Body\ntext\n");
+  }
+
+  #[test]
+  fn test_apply_markdown_rules_replace() {
+    let options = MarkdownPostProcessOptions {
+      replace: Some(vec![
+        MarkdownReplaceRule {
+          pattern: r"\[edit\]".to_string(),
+          replacement: None,
+        },
+        MarkdownReplaceRule {
+          pattern: r"v(\d+)".to_string(),
+          replacement: Some("version $1".to_string()),
+        },
+      ]),
+      ..Default::default()
+    };
+    assert_eq!(apply("Title [edit]\nv2\n", options), "Title \nversion 2\n");
+
+    let invalid = MarkdownPostProcessOptions {
+      replace: Some(vec![MarkdownReplaceRule {
+        pattern: "(".to_string(),
+        replacement: None,
+      }]),
+      ..Default::default()
+    };
+    assert!(_apply_markdown_rules(String::new(), &invalid).is_err());
+  }
+}
@@ -0,0 +1,298 @@
+//! Readability-style main content extraction.
+//!
+//! Scores block-level elements by the amount of prose they contain (text length, commas) and
+//! propagates the scores to their ancestors, penalizes link-heavy candidates, and keeps the best
+//! candidate together with related siblings.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::LazyLock;
+
+use kuchikiki::{parse_html, traits::TendrilSink, NodeRef};
+use regex::Regex;
+
+static UNLIKELY_CANDIDATES: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r"(?i)-ad-|^ad-|\bads?\b|banner|breadcrumbs|combx|comment|community|cookie|disqus|extra|footer|gdpr|header|legends|menu|modal|nav|pager|pagination|popup|promo|related|remark|replies|rss|share|shoutbox|sidebar|skyscraper|social|sponsor|subscribe|tweet",
+  )
+  .expect("UNLIKELY_CANDIDATES is a valid static regex pattern")
+});
+
+static MAYBE_CANDIDATE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"(?i)and|article|body|column|content|main|shadow")
+    .expect("MAYBE_CANDIDATE is a valid static regex pattern")
+});
+
+static POSITIVE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"(?i)article|body|content|entry|hentry|h-entry|main|page|post|text|blog|story")
+    .expect("POSITIVE is a valid static regex pattern")
+});
+
+static NEGATIVE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r"(?i)-ad-|hidden|^hid$|\bhid\b|banner|combx|comment|com-|contact|footer|gdpr|masthead|media|meta|outbrain|promo|related|scroll|share|shoutbox|sidebar|skyscraper|sponsor|shopping|tags|widget",
+  )
+  .expect("NEGATIVE is a valid static regex pattern")
+});
+
+const REMOVED_TAGS: &str =
+  "script, style, noscript, template, iframe, object, embed, form, nav, footer, aside, svg, canvas";
+const SCORED_TAGS: &str = "p, pre, td, blockquote, section, h2, h3, h4, h5, h6";
+const MIN_PARAGRAPH_LENGTH: usize = 25;
+
+fn node_key(node: &NodeRef) -> usize {
+  Rc::as_ptr(&node.0) as usize
+}
+
+fn tag_name(node: &NodeRef) -> Option<String> {
+  node
+    .as_element()
+    .map(|element| element.name.local.as_ref().to_string())
+}
+
+fn class_and_id(node: &NodeRef) -> String {
+  node
+    .as_element()
+    .map(|element| {
+      let attributes = element.attributes.borrow();
+      format!(
+        "{} {}",
+        attributes.get("class").unwrap_or(""),
+        attributes.get("id").unwrap_or("")
+      )
+    })
+    .unwrap_or_default()
+}
+
+fn class_weight(node: &NodeRef) -> f64 {
+  let class_and_id = class_and_id(node);
+  let mut weight = 0.0;
+  if NEGATIVE.is_match(&class_and_id) {
+    weight -= 25.0;
+  }
+  if POSITIVE.is_match(&class_and_id) {
+    weight += 25.0;
+  }
+  weight
+}
+
+fn initial_score(node: &NodeRef) -> f64 {
+  let base = match tag_name(node).as_deref() {
+    Some("div" | "article" | "main") => 5.0,
+    Some("pre" | "td" | "blockquote") => 3.0,
+    Some("address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form") => -3.0,
+    Some("h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th") => -5.0,
+    _ => 0.0,
+  };
+  base + class_weight(node)
+}
+
+fn link_density(node: &NodeRef) -> f64 {
+  let text_length = node.text_contents().trim().chars().count();
+  if text_length == 0 {
+    return 0.0;
+  }
+
+  let link_length: usize = node
+    .select("a")
+    .map(|links| {
+      links
+        .map(|link| link.text_contents().trim().chars().count())
+        .sum()
+    })
+    .unwrap_or(0);
+
+  link_length as f64 / text_length as f64
+}
+
+fn remove_unlikely_candidates(document: &NodeRef) {
+  if let Ok(elements) = document.select(REMOVED_TAGS) {
+    for element in elements.collect::<Vec<_>>() {
+      element.as_node().detach();
+    }
+  }
+
+  let Ok(elements) = document.select("*") else {
+    return;
+  };
+
+  for element in elements.collect::<Vec<_>>() {
+    let node = element.as_node();
+    if matches!(
+      tag_name(node).as_deref(),
+      Some("html" | "body" | "article" | "main" | "a")
+    ) {
+      continue;
+    }
+
+    let class_and_id = class_and_id(node);
+    if UNLIKELY_CANDIDATES.is_match(&class_and_id) && !MAYBE_CANDIDATE.is_match(&class_and_id) {
+      node.detach();
+    }
+  }
+}
+
+fn score_candidates(document: &NodeRef) -> HashMap<usize, (NodeRef, f64)> {
+  let mut scores: HashMap<usize, (NodeRef, f64)> = HashMap::new();
+
+  let Ok(elements) = document.select(SCORED_TAGS) else {
+    return scores;
+  };
+
+  for element in elements {
+    let text = element.text_contents();
+    let text = text.trim();
+    if text.chars().count() < MIN_PARAGRAPH_LENGTH {
+      continue;
+    }
+
+    let content_score = 1.0
+      + text.matches(',').count() as f64
+      + (text.chars().count() as f64 / 100.0).floor().min(3.0);
+
+    for (level, ancestor) in element
+      .as_node()
+      .ancestors()
+      .filter(|ancestor| ancestor.as_element().is_some())
+      .take(3)
+      .enumerate()
+    {
+      let divider = match level {
+        0 => 1.0,
+        1 => 2.0,
+        _ => level as f64 * 3.0,
+      };
+
+      scores
+        .entry(node_key(&ancestor))
+        .or_insert_with(|| (ancestor.clone(), initial_score(&ancestor)))
+        .1 += content_score / divider;
+    }
+  }
+
+  for (node, score) in scores.values_mut() {
+    *score *= 1.0 - link_density(node);
+  }
+
+  scores
+}
+
+/// Returns the main content of the page as an HTML fragment, falling back to the `<body>` when no
+/// candidate stands out.
+pub(crate) fn _extract_main_content(html: &str) -> String {
+  let document = parse_html().one(html);
+  remove_unlikely_candidates(&document);
+
+  let scores = score_candidates(&document);
+  let Some((top, top_score)) = scores
+    .values()
+    .filter(|(node, _)| !matches!(tag_name(node).as_deref(), Some("html" | "body")))
+    .max_by(|a, b| a.1.total_cmp(&b.1))
+    .cloned()
+  else {
+    return document
+      .select_first("body")
+      .map(|body| {
+        body
+          .as_node()
+          .children()
+          .map(|child| child.to_string())
+          .collect()
+      })
+      .unwrap_or_default();
+  };
+
+  let Some(parent) = top.parent() else {
+    return format!("<div>{}</div>", top.to_string());
+  };
+
+  let sibling_threshold = (top_score * 0.2).max(10.0);
+  let bonus = if class_and_id(&top).trim().is_empty() {
+    0.0
+  } else {
+    top_score * 0.2
+  };
+
+  let mut out = String::from("<div>");
+  for sibling in parent.children() {
+    if sibling == top {
+      out.push_str(&sibling.to_string());
+      continue;
+    }
+
+    let mut score = scores
+      .get(&node_key(&sibling))
+      .map_or(0.0, |(_, score)| *score);
+    if class_and_id(&sibling) == class_and_id(&top) && !class_and_id(&top).trim().is_empty() {
+      score += bonus;
+    }
+
+    let keep = score >= sibling_threshold
+      || (tag_name(&sibling).as_deref() == Some("p") && {
+        let text = sibling.text_contents();
+        let length = text.trim().chars().count();
+        let density = link_density(&sibling);
+        (length > 80 && density < 0.25) || (length > 0 && density == 0.0 && text.contains(". "))
+      });
+
+    if keep {
+      out.push_str(&sibling.to_string());
+    }
+  }
+  out.push_str("</div>");
+
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PARAGRAPH: &str = "The committee met on Tuesday to review the proposal, and after a long \
+    discussion about costs, timelines and staffing, it agreed to fund the first phase of the work.";
+
+  #[test]
+  fn test_extract_main_content_skips_navigation_and_sidebar() {
+    let html = format!(
+      r#"<html><body>
+        <nav><a href="/">Home</a> <a href="/news">News</a> Navigation menu with plenty of words</nav>
+        <div class="sidebar"><p>Sidebar: sign up for our newsletter, follow us, and read more.</p></div>
+        <div class="article-body"><p>{PARAGRAPH}</p><p>{PARAGRAPH}</p><p>{PARAGRAPH}</p></div>
+        <footer><p>Copyright notice, terms of service, privacy policy and contact details.</p></footer>
+      </body></html>"#
+    );
+
+    let content = _extract_main_content(&html);
+    assert!(content.contains("The committee met on Tuesday"));
+    assert!(!content.contains("Navigation menu"));
+    assert!(!content.contains("Sidebar:"));
+    assert!(!content.contains("Copyright notice"));
+  }
+
+  #[test]
+  fn test_extract_main_content_rejects_link_heavy_candidates() {
+    let links = (0..10)
+      .map(|i| {
+        format!(r#"<a href="/story/{i}">Another headline about something, number {i}</a>, "#)
+      })
+      .collect::<String>();
+    let html = format!(
+      r#"<html><body>
+        <div id="more-stories"><p>{links}</p><p>{links}</p></div>
+        <div id="story"><p>{PARAGRAPH}</p><p>{PARAGRAPH}</p></div>
+      </body></html>"#
+    );
+
+    let content = _extract_main_content(&html);
+    assert!(content.contains("The committee met on Tuesday"));
+    assert!(!content.contains("Another headline"));
+  }
+
+  #[test]
+  fn test_extract_main_content_falls_back_to_body() {
+    let html = "<html><body><h1>Hi</h1><span>Short</span></body></html>";
+    assert_eq!(_extract_main_content(html), "<h1>Hi</h1><span>Short</span>");
+
+    assert_eq!(_extract_main_content(""), "");
+  }
+}
@@ -562,6 +562,40 @@ describe("Crawl tests", () => {
     10 * scrapeTimeout,
   );
 
+  describe("Pattern validation", () => {
+    it.concurrent("should reject invalid includePaths patterns", async () => {
+      const response = await crawlStart(
+        {
+          url: "https://firecrawl.dev",
+          includePaths: ["^/blog/.*$", "/unclosed("],
+          limit: 1,
+        },
+        identity,
+      );
+
+      expect(response.statusCode).toBe(400);
+      expect(response.body.success).toBe(false);
+      expect(response.body.error).toContain("/unclosed(");
+      expect(response.body.details).toHaveLength(1);
+      expect(response.body.details[0].pattern).toBe("/unclosed(");
+    });
+
+    it.concurrent("should accept glob patterns", async () => {
+      const response = await crawlStart(
+        {
+          url: "https://firecrawl.dev",
+          includePaths: ["glob:/blog/**"],
+          excludePaths: ["glob:/blog/*/drafts/*"],
+          limit: 1,
+        },
+        identity,
+      );
+
+      expect(response.statusCode).toBe(200);
+      expect(response.body.success).toBe(true);
+    });
+  });
+
   describe("UUID validation", () => {
     it.concurrent(
       "should reject invalid UUID 'None' for crawl status",
@@ -1,6 +1,7 @@
 import path from "path";
 import fs from "fs";
 import { DocumentConverter, DocumentType } from "@mendable/firecrawl-rs";
+import { resolveDocumentType } from "../../../scraper/scrapeURL/engines/document";
 
 describe("Document Converter tests", () => {
   const samplesDir = path.join(process.cwd(), "samples");
@@ -57,4 +58,50 @@ describe("Document Converter tests", () => {
       expect(html).toBe(expectedHtml);
     });
   });
+
+  describe("document type detection", () => {
+    it.each([
+      { file: "sample.docx", type: DocumentType.Docx },
+      { file: "sample.odt", type: DocumentType.Odt },
+      { file: "sample.rtf", type: DocumentType.Rtf },
+      { file: "sample.xlsx", type: DocumentType.Xlsx },
+    ])("should detect $file from its contents", ({ file, type }) => {
+      const fileBuffer = fs.readFileSync(path.join(samplesDir, file));
+      expect(converter.detectDocumentType(new Uint8Array(fileBuffer))).toBe(
+        type,
+      );
+    });
+
+    it("should not detect plain text", () => {
+      expect(
+        converter.detectDocumentType(new TextEncoder().encode("hello")),
+      ).toBeNull();
+    });
+
+    it("should convert a DOCX served with a mislabelled content type", async () => {
+      const fileBuffer = fs.readFileSync(path.join(samplesDir, "sample.docx"));
+      const type = resolveDocumentType(
+        fileBuffer,
+        "application/msword",
+        "https://example.com/download?id=1",
+      );
+      expect(type).toBe(DocumentType.Docx);
+
+      const html = await converter.convertBufferToHtml(
+        new Uint8Array(fileBuffer),
+        type,
+      );
+      expect(html).toBe(expectedHtmlBase("DOCX"));
+    });
+
+    it("should fall back to the content type when the contents are unknown", () => {
+      expect(
+        resolveDocumentType(
+          new TextEncoder().encode("hello"),
+          "application/vnd.oasis.opendocument.text",
+          "https://example.com/file.docx",
+        ),
+      ).toBe(DocumentType.Odt);
+    });
+  });
 });
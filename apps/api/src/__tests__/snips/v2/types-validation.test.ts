@@ -956,6 +956,42 @@ describe("V2 Types Validation", () => {
         "Total wait time (waitFor + wait actions) cannot exceed",
       );
     });
+
+    it("should accept the codeBlocks format", () => {
+      const result = scrapeOptions.parse({
+        formats: [{ type: "markdown" }, { type: "codeBlocks" }],
+      });
+      expect(result.formats).toContainEqual({ type: "codeBlocks" });
+    });
+
+    it("should accept markdownOptions", () => {
+      const result = scrapeOptions.parse({
+        markdownOptions: {
+          replace: [{ pattern: "\\s+$", replacement: "" }],
+          stripSections: ["Related articles"],
+          collapseBlankLines: true,
+          headingOffset: 1,
+        },
+      });
+      expect(result.markdownOptions?.stripSections).toEqual([
+        "Related articles",
+      ]);
+      expect(result.markdownOptions?.headingOffset).toBe(1);
+    });
+
+    it("should reject a markdownOptions headingOffset above 5", () => {
+      expect(() =>
+        scrapeOptions.parse({ markdownOptions: { headingOffset: 6 } }),
+      ).toThrow();
+    });
+
+    it("should reject an invalid markdownOptions replace pattern", () => {
+      expect(() =>
+        scrapeOptions.parse({
+          markdownOptions: { replace: [{ pattern: "(unclosed" }] },
+        }),
+      ).toThrow("Invalid regex pattern");
+    });
   });
 
   describe("Edge cases", () => {
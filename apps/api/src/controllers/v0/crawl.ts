@@ -35,6 +35,7 @@ import { fromV0ScrapeOptions } from "../v2/types";
 import { isSelfHosted } from "../../lib/deployment";
 import { crawlGroup } from "../../services/worker/nuq";
 import { logRequest } from "../../services/logging/log_job";
+import { validatePatterns } from "@mendable/firecrawl-rs";
 
 export async function crawlController(req: Request, res: Response) {
   try {
@@ -101,24 +102,21 @@ export async function crawlController(req: Request, res: Response) {
     };
     const pageOptions = { ...defaultCrawlPageOptions, ...req.body.pageOptions };
 
-    if (Array.isArray(crawlerOptions.includes)) {
-      for (const x of crawlerOptions.includes) {
-        try {
-          new RegExp(x);
-        } catch (e) {
-          return res.status(400).json({ error: e.message });
-        }
-      }
-    }
-
-    if (Array.isArray(crawlerOptions.excludes)) {
-      for (const x of crawlerOptions.excludes) {
-        try {
-          new RegExp(x);
-        } catch (e) {
-          return res.status(400).json({ error: e.message });
-        }
-      }
+    const patternErrors = await validatePatterns([
+      ...(Array.isArray(crawlerOptions.includes)
+        ? crawlerOptions.includes
+        : []),
+      ...(Array.isArray(crawlerOptions.excludes)
+        ? crawlerOptions.excludes
+        : []),
+    ]);
+    if (patternErrors.length > 0) {
+      return res.status(400).json({
+        error: `Invalid includes/excludes pattern(s): ${patternErrors
+          .map(x => `"${x.pattern}" (${x.error})`)
+          .join(", ")}`,
+        details: patternErrors,
+      });
     }
 
     const limitCheck = req.body?.crawlerOptions?.limit ?? 1;
@@ -20,6 +20,7 @@ import { fromV1ScrapeOptions } from "../v2/types";
 import { checkPermissions } from "../../lib/permissions";
 import { crawlGroup } from "../../services/worker/nuq";
 import { logRequest } from "../../services/logging/log_job";
+import { validatePatterns } from "@mendable/firecrawl-rs";
 
 export async function crawlController(
   req: RequestWithAuth<{}, CrawlResponse, CrawlRequest>,
@@ -87,24 +88,22 @@ export async function crawlController(
   );
 
   // TODO: @rafa, is this right? copied from v0
-  if (Array.isArray(crawlerOptions.includePaths)) {
-    for (const x of crawlerOptions.includePaths) {
-      try {
-        new RegExp(x);
-      } catch (e) {
-        return res.status(400).json({ success: false, error: e.message });
-      }
-    }
-  }
-
-  if (Array.isArray(crawlerOptions.excludePaths)) {
-    for (const x of crawlerOptions.excludePaths) {
-      try {
-        new RegExp(x);
-      } catch (e) {
-        return res.status(400).json({ success: false, error: e.message });
-      }
-    }
+  const patternErrors = await validatePatterns([
+    ...(Array.isArray(crawlerOptions.includePaths)
+      ? crawlerOptions.includePaths
+      : []),
+    ...(Array.isArray(crawlerOptions.excludePaths)
+      ? crawlerOptions.excludePaths
+      : []),
+  ]);
+  if (patternErrors.length > 0) {
+    return res.status(400).json({
+      success: false,
+      error: `Invalid includePaths/excludePaths pattern(s): ${patternErrors
+        .map(x => `"${x.pattern}" (${x.error})`)
+        .join(", ")}`,
+      details: patternErrors,
+    });
   }
 
   const originalLimit = crawlerOptions.limit;
@@ -408,6 +408,32 @@ const locationSchema = z
   })
   .optional();
 
+// Patterns use Rust regex syntax, so lookarounds and backreferences are not
+// supported; the RegExp check only catches plain syntax errors up front.
+export const markdownOptionsSchema = z.strictObject({
+  replace: z
+    .strictObject({
+      pattern: z.string().refine(
+        pattern => {
+          try {
+            new RegExp(pattern);
+            return true;
+          } catch {
+            return false;
+          }
+        },
+        { message: "Invalid regex pattern" },
+      ),
+      replacement: z.string().optional(),
+    })
+    .array()
+    .max(50)
+    .optional(),
+  stripSections: z.string().array().max(50).optional(),
+  collapseBlankLines: z.boolean().optional(),
+  headingOffset: z.int().min(0).max(5).optional(),
+});
+
 function transformIframeSelector(selector: string): string {
   return selector.replace(/(?:^|[\s,])iframe(?=\s|$|[.#\[:,])/g, match => {
     const prefix = match.match(/^[\s,]/)?.[0] || "";
@@ -453,6 +479,7 @@ const baseScrapeOptions = z.strictObject({
     .transform(tags => tags.map(transformIframeSelector))
     .optional(),
   onlyMainContent: z.boolean().prefault(true),
+  markdownOptions: markdownOptionsSchema.optional(),
   timeout: z.int().positive().min(1000).optional(),
   waitFor: z.int().nonnegative().finite().max(60000).prefault(0),
   // Deprecate this to jsonOptions
@@ -854,6 +881,7 @@ const crawlerOptions = z.strictObject({
   deduplicateSimilarURLs: z.boolean().prefault(true),
   ignoreQueryParameters: z.boolean().prefault(false),
   regexOnFullURL: z.boolean().prefault(false),
+  detectCrawlerTraps: z.boolean().prefault(false),
   delay: z.number().positive().optional(),
 });
 
@@ -1063,6 +1091,7 @@ export type Document = {
     cachedAt?: string;
     creditsUsed?: number;
     postprocessorsUsed?: string[];
+    clientRedirects?: { url: string; kind: string }[]; // meta refresh / JS redirects followed, in order
     indexId?: string; // ID used to store the document in the index (GCS)
     concurrencyLimited?: boolean;
     concurrencyQueueDurationMs?: number;
@@ -1204,6 +1233,7 @@ export type CrawlErrorsResponse =
         error: string;
       }[];
       robotsBlocked: string[];
+      denialCounts: Record<string, number>;
     };
 
 type AuthObject = {
@@ -1331,6 +1361,7 @@ export function toLegacyCrawlerOptions(x: CrawlerOptions) {
     deduplicateSimilarURLs: x.deduplicateSimilarURLs,
     ignoreQueryParameters: x.ignoreQueryParameters,
     regexOnFullURL: x.regexOnFullURL,
+    detectCrawlerTraps: x.detectCrawlerTraps,
     maxDiscoveryDepth: x.maxDiscoveryDepth,
     currentDiscoveryDepth: 0,
     delay: x.delay,
@@ -1352,6 +1383,7 @@ export function toNewCrawlerOptions(x: any): CrawlerOptions {
     deduplicateSimilarURLs: x.deduplicateSimilarURLs,
     ignoreQueryParameters: x.ignoreQueryParameters,
     regexOnFullURL: x.regexOnFullURL,
+    detectCrawlerTraps: x.detectCrawlerTraps,
     maxDiscoveryDepth: x.maxDiscoveryDepth,
     delay: x.delay,
   };
@@ -1379,6 +1411,7 @@ function fromLegacyCrawlerOptions(
       deduplicateSimilarURLs: x.deduplicateSimilarURLs,
       ignoreQueryParameters: x.ignoreQueryParameters,
       regexOnFullURL: x.regexOnFullURL,
+      detectCrawlerTraps: x.detectCrawlerTraps,
       maxDiscoveryDepth: x.maxDiscoveryDepth,
       delay: x.delay,
     }),
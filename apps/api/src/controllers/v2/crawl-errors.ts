@@ -5,7 +5,11 @@ import {
   CrawlStatusParams,
   RequestWithAuth,
 } from "./types";
-import { getCrawl, getCrawlJobs } from "../../lib/crawl-redis";
+import {
+  getCrawl,
+  getCrawlJobs,
+  getDenialCounts,
+} from "../../lib/crawl-redis";
 import { redisEvictConnection } from "../../../src/services/redis";
 import { configDotenv } from "dotenv";
 import { supabase_rr_service } from "../../services/supabase";
@@ -72,6 +76,7 @@ export async function crawlErrorsController(
       robotsBlocked: await redisEvictConnection.smembers(
         "crawl:" + req.params.jobId + ":robots_blocked",
       ),
+      denialCounts: await getDenialCounts(req.params.jobId),
     });
   } else if (config.USE_DB_AUTHENTICATION) {
     // Check the requests table for the crawl/batch scrape request
@@ -150,6 +155,7 @@ export async function crawlErrorsController(
       robotsBlocked: await redisEvictConnection.smembers(
         "crawl:" + req.params.jobId + ":robots_blocked",
       ),
+      denialCounts: await getDenialCounts(req.params.jobId),
     });
   } else {
     return res.status(404).json({ success: false, error: "Job not found" });
@@ -22,6 +22,7 @@ import { checkPermissions } from "../../lib/permissions";
 import { buildPromptWithWebsiteStructure } from "../../lib/map-utils";
 import { crawlGroup } from "../../services/worker/nuq";
 import { logRequest } from "../../services/logging/log_job";
+import { validatePatterns } from "@mendable/firecrawl-rs";
 
 export async function crawlController(
   req: RequestWithAuth<{}, CrawlResponse, CrawlRequest>,
@@ -144,24 +145,22 @@ export async function crawlController(
     }
   }
 
-  if (Array.isArray(finalCrawlerOptions.includePaths)) {
-    for (const x of finalCrawlerOptions.includePaths) {
-      try {
-        new RegExp(x);
-      } catch (e) {
-        return res.status(400).json({ success: false, error: e.message });
-      }
-    }
-  }
-
-  if (Array.isArray(finalCrawlerOptions.excludePaths)) {
-    for (const x of finalCrawlerOptions.excludePaths) {
-      try {
-        new RegExp(x);
-      } catch (e) {
-        return res.status(400).json({ success: false, error: e.message });
-      }
-    }
+  const patternErrors = await validatePatterns([
+    ...(Array.isArray(finalCrawlerOptions.includePaths)
+      ? finalCrawlerOptions.includePaths
+      : []),
+    ...(Array.isArray(finalCrawlerOptions.excludePaths)
+      ? finalCrawlerOptions.excludePaths
+      : []),
+  ]);
+  if (patternErrors.length > 0) {
+    return res.status(400).json({
+      success: false,
+      error: `Invalid includePaths/excludePaths pattern(s): ${patternErrors
+        .map(x => `"${x.pattern}" (${x.error})`)
+        .join(", ")}`,
+      details: patternErrors,
+    });
   }
 
   const originalLimit = finalCrawlerOptions.limit;
@@ -14,6 +14,7 @@ import {
 import {
   agentOptionsExtract,
   AuthCreditUsageChunk,
+  markdownOptionsSchema,
   ScrapeOptions as V1ScrapeOptions,
 } from "../v1/types";
 import type { InternalOptions } from "../../scraper/scrapeURL";
@@ -406,6 +407,7 @@ export type FormatObject =
   | { type: "rawHtml" }
   | { type: "links" }
   | { type: "images" }
+  | { type: "codeBlocks" }
   | { type: "summary" }
   | JsonFormatWithOptions
   | ChangeTrackingFormatWithOptions
@@ -497,6 +499,7 @@ const baseScrapeOptions = z.strictObject({
           z.strictObject({ type: z.literal("rawHtml") }),
           z.strictObject({ type: z.literal("links") }),
           z.strictObject({ type: z.literal("images") }),
+          z.strictObject({ type: z.literal("codeBlocks") }),
           z.strictObject({ type: z.literal("summary") }),
           jsonFormatWithOptions,
           changeTrackingFormatWithOptions,
@@ -528,6 +531,7 @@ const baseScrapeOptions = z.strictObject({
     .transform(tags => tags.map(transformIframeSelector))
     .optional(),
   onlyMainContent: z.boolean().prefault(true),
+  markdownOptions: markdownOptionsSchema.optional(),
   timeout: z.int().positive().min(1000).optional(),
   waitFor: z.int().nonnegative().max(60000).prefault(0),
   mobile: z.boolean().prefault(false),
@@ -876,6 +880,7 @@ export const crawlerOptions = z.strictObject({
   deduplicateSimilarURLs: z.boolean().prefault(true),
   ignoreQueryParameters: z.boolean().prefault(false),
   regexOnFullURL: z.boolean().prefault(false),
+  detectCrawlerTraps: z.boolean().prefault(false),
   delay: z.number().positive().optional(),
 });
 
@@ -970,6 +975,11 @@ export type Document = {
   rawHtml?: string;
   links?: string[];
   images?: string[];
+  codeBlocks?: {
+    language?: string;
+    content: string;
+    heading?: string; // closest heading above the block
+  }[];
   screenshot?: string;
   extract?: any;
   json?: any;
@@ -1060,6 +1070,7 @@ export type Document = {
     cachedAt?: string;
     creditsUsed?: number;
     postprocessorsUsed?: string[];
+    clientRedirects?: { url: string; kind: string }[]; // meta refresh / JS redirects followed, in order
     indexId?: string; // ID used to store the document in the index (GCS)
     concurrencyLimited?: boolean;
     concurrencyQueueDurationMs?: number;
@@ -1235,6 +1246,7 @@ export type CrawlErrorsResponse =
         error: string;
       }[];
       robotsBlocked: string[];
+      denialCounts: Record<string, number>;
     };
 
 type AuthObject = {
@@ -1292,6 +1304,7 @@ export function toV0CrawlerOptions(x: CrawlerOptions) {
     deduplicateSimilarURLs: x.deduplicateSimilarURLs,
     ignoreQueryParameters: x.ignoreQueryParameters,
     regexOnFullURL: x.regexOnFullURL,
+    detectCrawlerTraps: x.detectCrawlerTraps,
     maxDiscoveryDepth: x.maxDiscoveryDepth,
     currentDiscoveryDepth: 0,
     delay: x.delay,
@@ -1311,6 +1324,7 @@ export function toV2CrawlerOptions(x: any): CrawlerOptions {
     deduplicateSimilarURLs: x.deduplicateSimilarURLs,
     ignoreQueryParameters: x.ignoreQueryParameters,
     regexOnFullURL: x.regexOnFullURL,
+    detectCrawlerTraps: x.detectCrawlerTraps,
     maxDiscoveryDepth: x.maxDiscoveryDepth,
     delay: x.delay,
   };
@@ -1336,6 +1350,7 @@ function fromV0CrawlerOptions(
       deduplicateSimilarURLs: x.deduplicateSimilarURLs,
       ignoreQueryParameters: x.ignoreQueryParameters,
       regexOnFullURL: x.regexOnFullURL,
+      detectCrawlerTraps: x.detectCrawlerTraps,
       maxDiscoveryDepth: x.maxDiscoveryDepth,
       delay: x.delay,
     }),
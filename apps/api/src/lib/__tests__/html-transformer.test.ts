@@ -1,4 +1,6 @@
 import {
+  detectChallengePage,
+  detectClientRedirect,
   extractLinks,
   extractMetadata,
   transformHtml,
@@ -231,6 +233,48 @@ describe("HTML Transformer", () => {
       });
     });
 
+    it("should resolve a relative canonical link against the page URL", async () => {
+      const html = `
+        <html>
+          <head>
+            <link rel="canonical" href="/docs/page">
+          </head>
+          <body></body>
+        </html>
+      `;
+      const metadata = await extractMetadata(
+        html,
+        "https://example.com/docs/page?ref=nav",
+      );
+      expect(metadata.canonicalUrl).toBe("https://example.com/docs/page");
+    });
+
+    it("should match canonical as one of several rel tokens", async () => {
+      const html = `
+        <html>
+          <head>
+            <link rel="Canonical alternate" href="https://example.com/canonical">
+          </head>
+          <body></body>
+        </html>
+      `;
+      const metadata = await extractMetadata(html);
+      expect(metadata.canonicalUrl).toBe("https://example.com/canonical");
+    });
+
+    it("should ignore a canonical link with an empty href", async () => {
+      const html = `
+        <html>
+          <head>
+            <link rel="canonical" href="  ">
+          </head>
+          <body></body>
+        </html>
+      `;
+      const metadata = await extractMetadata(html, "https://example.com/page");
+      expect(metadata.canonicalUrl).toBeUndefined();
+    });
+
     it("should handle metadata with special characters and encoding", async () => {
       const html = `
         <html>
@@ -487,4 +531,165 @@ describe("HTML Transformer", () => {
       expect(result).toContain("https://example.com/#q2");
     });
   });
+
+  describe("detectChallengePage", () => {
+    const page = (title: string, body: string) =>
+      `<html><head><title>${title}</title></head><body>${body}</body></html>`;
+
+    it.each([
+      [
+        "cloudflare",
+        page(
+          "Just a moment...",
+          "<script>window._cf_chl_opt = {};</script><p>Checking your browser</p>",
+        ),
+      ],
+      [
+        "cloudflare",
+        page(
+          "Attention Required! | Cloudflare",
+          '<div id="cf-error-details">Sorry, you have been blocked</div>',
+        ),
+      ],
+      [
+        "datadome",
+        page(
+          "example.com",
+          '<script src="https://ct.captcha-delivery.com/c.js"></script>',
+        ),
+      ],
+      [
+        "perimeterx",
+        page("Access to this page has been denied", '<div id="px-captcha"></div>'),
+      ],
+      [
+        "imperva",
+        page(
+          "Pardon Our Interruption",
+          '<script src="/_Incapsula_Resource?SWJIYLWA=1"></script>',
+        ),
+      ],
+      [
+        "sucuri",
+        page(
+          "You are being redirected...",
+          "<script>sucuri_cloudproxy_js='';</script>",
+        ),
+      ],
+      [
+        "akamai",
+        page(
+          "Access Denied",
+          'Reference #18.1 <a href="https://errors.edgesuite.net/18.1">more</a>',
+        ),
+      ],
+      [
+        "generic",
+        page("Access Denied", '<form><div class="g-recaptcha"></div></form>'),
+      ],
+    ])("should detect %s challenge pages", async (provider, html) => {
+      expect(await detectChallengePage(html)).toBe(provider);
+    });
+
+    it.each([
+      "Security Checklist for Small Businesses",
+      "Access Denied Records in NTFS",
+      "Just a moment of silence",
+    ])("should not flag ordinary pages titled %p", async title => {
+      expect(
+        await detectChallengePage(
+          page(
+            title,
+            '<p>Short article.</p><form><input name="captcha"></form>',
+          ),
+        ),
+      ).toBeNull();
+    });
+
+    it("should require a second signal for generic titles", async () => {
+      expect(
+        await detectChallengePage(
+          page(
+            "Access Denied",
+            "<p>You do not have permission to view this folder.</p>",
+          ),
+        ),
+      ).toBeNull();
+    });
+
+    it("should ignore markers on pages with real content", async () => {
+      const html = page(
+        "Blog",
+        `<div id="px-captcha"></div><p>${"Lorem ipsum dolor sit amet. ".repeat(60)}</p>`,
+      );
+      expect(await detectChallengePage(html)).toBeNull();
+    });
+  });
+
+  describe("detectClientRedirect", () => {
+    const url = "https://example.com/start";
+
+    it("should detect an immediate meta refresh", async () => {
+      const html =
+        '<html><head><meta http-equiv="refresh" content="0; url=/next"></head></html>';
+      expect(await detectClientRedirect(html, url)).toEqual({
+        url: "https://example.com/next",
+        kind: "meta_refresh",
+      });
+    });
+
+    it("should ignore a meta refresh with a long delay", async () => {
+      const html =
+        '<html><head><meta http-equiv="refresh" content="60; url=/next"></head></html>';
+      expect(await detectClientRedirect(html, url)).toBeNull();
+    });
+
+    it("should ignore a meta refresh back to the same page", async () => {
+      const html =
+        '<html><head><meta http-equiv="refresh" content="5"></head></html>';
+      expect(await detectClientRedirect(html, url)).toBeNull();
+    });
+
+    it("should detect a location assignment", async () => {
+      const html =
+        '<html><body><script>window.location.href = "/next";</script></body></html>';
+      expect(await detectClientRedirect(html, url)).toEqual({
+        url: "https://example.com/next",
+        kind: "javascript",
+      });
+    });
+
+    it("should detect location.replace", async () => {
+      const html =
+        "<html><body><script>location.replace('https://other.com/')</script></body></html>";
+      expect(await detectClientRedirect(html, url)).toEqual({
+        url: "https://other.com/",
+        kind: "javascript",
+      });
+    });
+
+    it.each([
+      'var geolocation = "eu";',
+      "var allocation='foo';",
+      "geolocation.assign('/foo');",
+      'if (location == "/start") {}',
+    ])("should not treat %p as a redirect", async script => {
+      const html = `<html><body><script>${script}</script></body></html>`;
+      expect(await detectClientRedirect(html, url)).toBeNull();
+    });
+
+    it("should ignore a meta refresh to a non-web scheme", async () => {
+      const html =
+        '<html><head><meta http-equiv="refresh" content="0; url=javascript:alert(1)"></head></html>';
+      expect(await detectClientRedirect(html, url)).toBeNull();
+    });
+
+    it("should not count script text towards the page length", async () => {
+      const html = `<html><body><script>var config = "${"x".repeat(1000)}";</script><script>location.href = "/next";</script><p>Redirecting...</p></body></html>`;
+      expect(await detectClientRedirect(html, url)).toEqual({
+        url: "https://example.com/next",
+        kind: "javascript",
+      });
+    });
+  });
 });
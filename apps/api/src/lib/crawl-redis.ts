@@ -60,6 +60,35 @@ export async function recordRobotsBlocked(crawlId: string, url: string) {
   );
 }
 
+export async function recordDenialCounts(
+  crawlId: string,
+  denialCounts: Record<string, number>,
+) {
+  const entries = Object.entries(denialCounts);
+  if (entries.length === 0) return;
+
+  const pipeline = redisEvictConnection.pipeline();
+  for (const [reason, count] of entries) {
+    pipeline.hincrby("crawl:" + crawlId + ":denial_counts", reason, count);
+  }
+  pipeline.expire("crawl:" + crawlId + ":denial_counts", 24 * 60 * 60);
+  await pipeline.exec();
+}
+
+export async function getDenialCounts(
+  crawlId: string,
+): Promise<Record<string, number>> {
+  const counts = await redisEvictConnection.hgetall(
+    "crawl:" + crawlId + ":denial_counts",
+  );
+  return Object.fromEntries(
+    Object.entries(counts).map(([reason, count]) => [
+      reason,
+      parseInt(count, 10),
+    ]),
+  );
+}
+
 export async function markCrawlActive(id: string) {
   await redisEvictConnection.sadd("active_crawls", id);
 }
@@ -544,6 +573,7 @@ export function crawlToCrawler(
     ignoreRobotsTxt:
       teamFlags?.ignoreRobots ?? sc.crawlerOptions?.ignoreRobotsTxt ?? false,
     regexOnFullURL: sc.crawlerOptions?.regexOnFullURL ?? false,
+    detectCrawlerTraps: sc.crawlerOptions?.detectCrawlerTraps ?? false,
     maxDiscoveryDepth: sc.crawlerOptions?.maxDiscoveryDepth,
     currentDiscoveryDepth: crawlerOptions?.currentDiscoveryDepth ?? 0,
     zeroDataRetention: (teamFlags?.forceZDR || sc.zeroDataRetention) ?? false,
@@ -7,7 +7,10 @@ import type { Logger } from "winston";
 import { stat } from "fs/promises";
 import { HTML_TO_MARKDOWN_PATH } from "../natives";
 import { convertHTMLToMarkdownWithHttpService } from "./html-to-markdown-client";
-import { postProcessMarkdown } from "@mendable/firecrawl-rs";
+import {
+  MarkdownPostProcessOptions,
+  postProcessMarkdown,
+} from "@mendable/firecrawl-rs";
 
 // TODO: add a timeout to the Go parser
 
@@ -51,11 +54,33 @@ class GoMarkdownConverter {
   }
 }
 
+// A pattern the Rust regex engine rejects should not cost the whole markdown,
+// so the user's rules are dropped rather than failing the conversion.
+async function applyPostProcessing(
+  markdownContent: string,
+  markdownOptions: MarkdownPostProcessOptions | undefined,
+  contextLogger: Logger,
+): Promise<string> {
+  try {
+    return await postProcessMarkdown(markdownContent, markdownOptions);
+  } catch (error) {
+    if (markdownOptions === undefined) {
+      throw error;
+    }
+    contextLogger.warn(
+      "Failed to apply markdownOptions, post-processing without them",
+      { error },
+    );
+    return await postProcessMarkdown(markdownContent);
+  }
+}
+
 export async function parseMarkdown(
   html: string | null | undefined,
   context?: {
     logger?: Logger;
     requestId?: string;
+    markdownOptions?: MarkdownPostProcessOptions;
   },
 ): Promise<string> {
   if (!html) {
@@ -64,6 +89,7 @@ export async function parseMarkdown(
 
   const contextLogger = context?.logger || logger;
   const requestId = context?.requestId;
+  const markdownOptions = context?.markdownOptions;
 
   // Try HTTP service first if enabled
   if (config.HTML_TO_MARKDOWN_SERVICE_URL) {
@@ -72,7 +98,11 @@ export async function parseMarkdown(
         logger: contextLogger,
         requestId,
       });
-      markdownContent = await postProcessMarkdown(markdownContent);
+      markdownContent = await applyPostProcessing(
+      markdownContent,
+      markdownOptions,
+      contextLogger,
+    );
       return markdownContent;
     } catch (error) {
       contextLogger.error(
@@ -92,7 +122,11 @@ export async function parseMarkdown(
     if (config.USE_GO_MARKDOWN_PARSER) {
       const converter = await GoMarkdownConverter.getInstance();
       let markdownContent = await converter.convertHTMLToMarkdown(html);
-      markdownContent = await postProcessMarkdown(markdownContent);
+      markdownContent = await applyPostProcessing(
+      markdownContent,
+      markdownOptions,
+      contextLogger,
+    );
       return markdownContent;
     }
   } catch (error) {
@@ -140,7 +174,11 @@ export async function parseMarkdown(
 
   try {
     let markdownContent = await turndownService.turndown(html);
-    markdownContent = await postProcessMarkdown(markdownContent);
+    markdownContent = await applyPostProcessing(
+      markdownContent,
+      markdownOptions,
+      contextLogger,
+    );
 
     return markdownContent;
   } catch (error) {
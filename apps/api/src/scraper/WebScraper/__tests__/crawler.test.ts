@@ -1,5 +1,5 @@
 // crawler.test.ts
-import { WebCrawler } from "../crawler";
+import { compilePattern, WebCrawler } from "../crawler";
 import axios from "axios";
 import robotsParser from "robots-parser";
 
@@ -153,3 +153,25 @@ describe("WebCrawler", () => {
     ).toBe(true);
   });
 });
+
+describe("compilePattern", () => {
+  it("should treat plain patterns as regular expressions", () => {
+    expect(compilePattern("^/blog/.*$").test("/blog/post")).toBe(true);
+    expect(() => compilePattern("(")).toThrow();
+  });
+
+  it("should translate glob patterns", () => {
+    expect(compilePattern("glob:/docs/*").test("/docs/intro")).toBe(true);
+    expect(compilePattern("glob:/docs/*").test("/docs/api/v1")).toBe(false);
+    expect(compilePattern("glob:/docs/**").test("/docs/api/v1")).toBe(true);
+    expect(compilePattern("glob:/file?.html").test("/file1.html")).toBe(true);
+    expect(compilePattern("glob:/file?.html").test("/file1xhtml")).toBe(false);
+  });
+
+  it("should let **/ match zero segments", () => {
+    const pattern = compilePattern("glob:/a/**/b");
+    expect(pattern.test("/a/b")).toBe(true);
+    expect(pattern.test("/a/x/y/b")).toBe(true);
+    expect(pattern.test("/ab")).toBe(false);
+  });
+});
@@ -20,6 +20,40 @@ import { filterLinks, filterUrl } from "@mendable/firecrawl-rs";
 export const SITEMAP_LIMIT = 25;
 const SITEMAP_MAX_AGE = 7 * 24 * 60 * 60 * 1000;
 
+/**
+ * Mirrors `compile_pattern` in the native crawler: patterns prefixed with `glob:` use shell-style
+ * globbing (`*` within a path segment, `**` across segments, `?` for a single character) and must
+ * match the whole target; anything else is a regular expression.
+ */
+export function compilePattern(pattern: string): RegExp {
+  if (!pattern.startsWith("glob:")) {
+    return new RegExp(pattern);
+  }
+
+  const glob = pattern.slice("glob:".length);
+  let regex = "^";
+  for (let i = 0; i < glob.length; i++) {
+    const c = glob[i];
+    if (c === "*" && glob[i + 1] === "*") {
+      i++;
+      // `**/` also matches zero segments, so `/a/**/b` matches `/a/b`
+      if (glob[i + 1] === "/") {
+        i++;
+        regex += "(?:.*/)?";
+      } else {
+        regex += ".*";
+      }
+    } else if (c === "*") {
+      regex += "[^/]*";
+    } else if (c === "?") {
+      regex += "[^/]";
+    } else {
+      regex += c.replace(/[.*+?^${}()|[\]\\/-]/g, "\\$&");
+    }
+  }
+  return new RegExp(regex + "$");
+}
+
 interface FilterResult {
   allowed: boolean;
   url?: string;
@@ -38,11 +72,16 @@ enum DenialReason {
   EXTERNAL_LINK = "This URL points to a different domain than the one being crawled, and external links are disabled. By default, Firecrawl only crawls URLs on the same domain as the starting URL. To crawl external links, set allowExternalLinks: true in your crawl request.",
   SECTION_LINK = "This URL contains a section anchor (#) and points to a specific section of a page rather than a separate page. Firecrawl treats these as duplicates of the base URL and skips them to avoid crawling the same content multiple times.",
   NON_WEB_PROTOCOL = "This URL uses a non-web protocol (such as mailto:, tel:, ftp:, ssh:, file:, or telnet:) that Firecrawl cannot scrape. Firecrawl only supports HTTP and HTTPS protocols.",
+  LIMIT = "This URL was not crawled because the crawl already found as many pages as the limit you configured. To crawl more pages, increase the limit value in your crawl request.",
+  CRAWLER_TRAP = "This URL looks like a crawler trap (a session ID in the URL, a repeating path segment, a calendar page with date parameters, or an unusually long query string) that would generate an endless number of near-duplicate pages. To crawl it anyway, set detectCrawlerTraps: false in your crawl request.",
 }
 
 interface FilterLinksResult {
   links: string[];
   denialReasons: Map<string, string>;
+  // Denied links per reason code (e.g. ROBOTS_TXT). Only the native filter
+  // reports these.
+  denialCounts?: Record<string, number>;
 }
 
 export class WebCrawler {
@@ -66,6 +105,7 @@ export class WebCrawler {
   private allowSubdomains: boolean;
   private ignoreRobotsTxt: boolean;
   private regexOnFullURL: boolean;
+  private detectCrawlerTraps: boolean;
   private logger: typeof _logger;
   private sitemapsHit: Set<string> = new Set();
   private maxDiscoveryDepth: number | undefined;
@@ -89,6 +129,7 @@ export class WebCrawler {
     allowSubdomains = false,
     ignoreRobotsTxt = false,
     regexOnFullURL = false,
+    detectCrawlerTraps = false,
     maxDiscoveryDepth,
     currentDiscoveryDepth,
     zeroDataRetention,
@@ -109,6 +150,7 @@ export class WebCrawler {
     allowSubdomains?: boolean;
     ignoreRobotsTxt?: boolean;
     regexOnFullURL?: boolean;
+    detectCrawlerTraps?: boolean;
     maxDiscoveryDepth?: number;
     currentDiscoveryDepth?: number;
     zeroDataRetention?: boolean;
@@ -133,6 +175,7 @@ export class WebCrawler {
     this.allowSubdomains = allowSubdomains ?? false;
     this.ignoreRobotsTxt = ignoreRobotsTxt ?? false;
     this.regexOnFullURL = regexOnFullURL ?? false;
+    this.detectCrawlerTraps = detectCrawlerTraps ?? false;
     this.zeroDataRetention = zeroDataRetention ?? false;
     this.logger = _logger.child({
       crawlId: this.jobId,
@@ -190,6 +233,7 @@ export class WebCrawler {
         robotsTxt: this.robotsTxt,
         allowExternalContentLinks: this.allowExternalContentLinks,
         allowSubdomains: this.allowSubdomains,
+        detectCrawlerTraps: this.detectCrawlerTraps,
       });
 
       const fancyDenialReasons = new Map<string, string>();
@@ -214,7 +258,7 @@ export class WebCrawler {
           case "EXCLUDE_PATTERN":
             const matchingExclude = this.excludes.find(p => {
               try {
-                return new RegExp(p).test(excincPath);
+                return compilePattern(p).test(excincPath);
               } catch {
                 return false;
               }
@@ -263,6 +307,7 @@ export class WebCrawler {
       return {
         links: res.links,
         denialReasons: fancyDenialReasons,
+        denialCounts: res.denialCounts,
       };
     } catch (error) {
       this.logger.error("Error filtering links in Rust, falling back to JS", {
@@ -323,7 +368,7 @@ export class WebCrawler {
         // Check if the link should be excluded
         if (this.excludes.length > 0 && this.excludes[0] !== "") {
           const matchingPattern = this.excludes.find(excludePattern =>
-            new RegExp(excludePattern).test(excincPath),
+            compilePattern(excludePattern).test(excincPath),
           );
           if (matchingPattern) {
             if (config.FIRECRAWL_DEBUG_FILTER_LINKS) {
@@ -341,7 +386,7 @@ export class WebCrawler {
         if (this.includes.length > 0 && this.includes[0] !== "") {
           if (
             !this.includes.some(includePattern =>
-              new RegExp(includePattern).test(excincPath),
+              compilePattern(includePattern).test(excincPath),
             )
           ) {
             if (config.FIRECRAWL_DEBUG_FILTER_LINKS) {
@@ -73,6 +73,23 @@ function getDocumentTypeFromContentType(
   return null;
 }
 
+/**
+ * The file contents are trusted over the Content-Type header, since servers routinely label
+ * .docx files as application/msword and the like. The header and then the URL extension are
+ * only used when the contents are not recognised.
+ */
+export function resolveDocumentType(
+  buffer: Uint8Array,
+  contentType: string | null,
+  url: string,
+): DocumentType {
+  return (
+    converter.detectDocumentType(buffer) ??
+    getDocumentTypeFromContentType(contentType) ??
+    getDocumentTypeFromUrl(url)
+  );
+}
+
 function isValidDocumentContentType(contentType: string | null): boolean {
   if (!contentType) return false;
 
@@ -136,9 +153,11 @@ export async function scrapeDocument(meta: Meta): Promise<EngineScrapeResult> {
       }
     }
 
-    const documentType =
-      getDocumentTypeFromContentType(response.headers.get("content-type")) ??
-      getDocumentTypeFromUrl(response.url);
+    const documentType = resolveDocumentType(
+      buffer,
+      response.headers.get("content-type"),
+      response.url,
+    );
 
     const html = await converter.convertBufferToHtml(
       new Uint8Array(buffer),
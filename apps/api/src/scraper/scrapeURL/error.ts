@@ -56,6 +56,17 @@ export class AddFeatureError extends Error {
   }
 }
 
+export class ClientRedirectError extends Error {
+  public url: string;
+  public kind: string;
+
+  constructor(url: string, kind: string) {
+    super("Client-side redirect detected to " + url);
+    this.url = url;
+    this.kind = kind;
+  }
+}
+
 export class RemoveFeatureError extends Error {
   public featureFlags: FeatureFlag[];
 
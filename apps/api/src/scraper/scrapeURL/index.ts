@@ -27,6 +27,7 @@ import { hasFormatOfType } from "../../lib/format-utils";
 import {
   ActionError,
   AddFeatureError,
+  ClientRedirectError,
   EngineError,
   NoEnginesLeftError,
   PDFAntibotError,
@@ -57,6 +58,10 @@ import { loadMock, MockState } from "./lib/mock";
 import { CostTracking } from "../../lib/cost-tracking";
 import { getEngineForUrl } from "../WebScraper/utils/engine-forcing";
 import { useIndex } from "../../services/index";
+import {
+  detectChallengePage,
+  detectClientRedirect,
+} from "@mendable/firecrawl-rs";
 import {
   fetchRobotsTxt,
   createRobotsChecker,
@@ -120,6 +125,8 @@ export type Meta = {
     | undefined; // undefined: no prefetch yet, null: prefetch came back empty
   costTracking: CostTracking;
   winnerEngine?: Engine;
+  challengedEngines?: Engine[]; // engines that were served an anti-bot challenge page
+  clientRedirects?: { url: string; kind: string }[]; // client-side redirects followed so far
   abortHandle?: NodeJS.Timeout;
 };
 
@@ -330,11 +337,13 @@ type EngineScrapeResultWithContext = {
 };
 
 const MAX_HTML_SIZE_FOR_MARKDOWN_CHECK = 300 * 1024; // 300KB
+const MAX_CLIENT_REDIRECTS = 3;
 
 async function scrapeURLLoopIter(
   meta: Meta,
   engine: Engine,
   snipeAbort,
+  onChallenge: (result: EngineScrapeResult) => void,
 ): Promise<EngineScrapeResult> {
   const abort = meta.abort.child(snipeAbort);
   try {
@@ -406,9 +415,13 @@ async function scrapeURLLoopIter(
       (engineResult.statusCode >= 200 && engineResult.statusCode < 300) ||
       engineResult.statusCode === 304;
     const hasNoPageError = engineResult.error === undefined;
-    const isLikelyProxyError = [401, 403, 429].includes(
-      engineResult.statusCode,
-    );
+    const challengeProvider =
+      engineResult.html && !shouldSkipMarkdownCheck
+        ? await detectChallengePage(engineResult.html)
+        : null;
+    const isLikelyProxyError =
+      [401, 403, 429].includes(engineResult.statusCode) ||
+      challengeProvider !== null;
 
     if (
       isLikelyProxyError &&
@@ -422,12 +435,50 @@ async function scrapeURLLoopIter(
         {
           factors: { isLongEnough, isGoodStatusCode, hasNoPageError },
           statusCode: engineResult.statusCode,
+          challengeProvider,
           length: engineResult.html?.trim().length ?? 0,
         },
       );
       throw new AddFeatureError(["stealthProxy"]);
     }
 
+    if (challengeProvider !== null) {
+      meta.logger.warn(
+        "Scrape via " + engine + " hit an anti-bot challenge page.",
+        {
+          challengeProvider,
+          statusCode: engineResult.statusCode,
+        },
+      );
+      meta.challengedEngines = [...(meta.challengedEngines ?? []), engine];
+
+      // Kept aside in case every other engine fails, since a challenge page beats no result
+      onChallenge(engineResult);
+      throw new EngineUnsuccessfulError(engine);
+    }
+
+    // Stub pages that bounce to another URL via meta refresh or a JS location
+    // assignment are re-scraped at the target, up to MAX_CLIENT_REDIRECTS times
+    if (
+      engineResult.html &&
+      isGoodStatusCode &&
+      challengeProvider === null &&
+      !shouldSkipMarkdownCheck &&
+      (meta.clientRedirects?.length ?? 0) < MAX_CLIENT_REDIRECTS
+    ) {
+      const redirect = await detectClientRedirect(
+        engineResult.html,
+        engineResult.url,
+      );
+      if (
+        redirect !== null &&
+        redirect.url !== meta.url &&
+        !meta.clientRedirects?.some(x => x.url === redirect.url)
+      ) {
+        throw new ClientRedirectError(redirect.url, redirect.kind);
+      }
+    }
+
     // NOTE: TODO: what to do when status code is bad is tough...
     // we cannot just rely on text because error messages can be brief and not hit the limit
     // should we just use all the fallbacks and pick the one with the longest text? - mogery
@@ -534,6 +585,7 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
     const remainingEngines = [...fallbackList];
     let enginePromises: EngineBundlePromise[] = [];
     const enginesAttempted: string[] = [];
+    const challengedResults: EngineScrapeResultWithContext[] = [];
 
     meta.abort.throwIfAborted();
 
@@ -573,7 +625,17 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
             return {
               engine,
               unsupportedFeatures,
-              result: await scrapeURLLoopIter(meta, engine, snipeAbort),
+              result: await scrapeURLLoopIter(
+                meta,
+                engine,
+                snipeAbort,
+                result =>
+                  challengedResults.push({
+                    engine,
+                    unsupportedFeatures,
+                    result,
+                  }),
+              ),
             };
           } catch (error) {
             throw new WrappedEngineError(engine, error);
@@ -640,6 +702,7 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
             } else if (
               error.error instanceof AddFeatureError ||
               error.error instanceof RemoveFeatureError ||
+              error.error instanceof ClientRedirectError ||
               error.error instanceof SiteError ||
               error.error instanceof SSLError ||
               error.error instanceof DNSResolutionError ||
@@ -693,7 +756,8 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
             // Otherwise, just keep racing
           } else if (
             error instanceof AddFeatureError ||
-            error instanceof RemoveFeatureError
+            error instanceof RemoveFeatureError ||
+            error instanceof ClientRedirectError
           ) {
             throw error;
           } else if (error instanceof WaterfallNextEngineSignal) {
@@ -734,6 +798,17 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
 
     snipeAbortController.abort();
 
+    if (result === null && challengedResults.length > 0) {
+      meta.logger.warn(
+        "All engines failed, falling back to the first challenge page result.",
+        {
+          engine: challengedResults[0].engine,
+          enginesAttempted,
+        },
+      );
+      result = challengedResults[0];
+    }
+
     if (result === null) {
       setSpanAttributes(span, {
         "engine.no_engines_left": true,
@@ -816,6 +891,7 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
               }
           : {}),
         postprocessorsUsed: engineResult.postprocessorsUsed,
+        clientRedirects: meta.clientRedirects,
       },
     };
 
@@ -831,6 +907,20 @@ async function scrapeURLLoop(meta: Meta): Promise<ScrapeUrlResponse> {
           : warning;
     }
 
+    if ((meta.challengedEngines?.length ?? 0) > 0) {
+      const warning = meta.challengedEngines!.includes(result.engine)
+        ? "The page appears to be an anti-bot challenge page and every other engine failed -- the content may not be the page you requested."
+        : "An anti-bot challenge page was served on the first attempt, so the page was re-scraped with a different engine.";
+      meta.logger.warn(warning, {
+        engine: result.engine,
+        challengedEngines: meta.challengedEngines,
+      });
+      document.warning =
+        document.warning !== undefined
+          ? document.warning + " " + warning
+          : warning;
+    }
+
     // NOTE: for sitemap, we don't need all the transformers, need to skip unused ones
     document = await executeTransformers(meta, document);
 
@@ -1028,6 +1118,16 @@ export async function scrapeURL(
                 x => !error.featureFlags.includes(x),
               ),
             );
+          } else if (error instanceof ClientRedirectError) {
+            meta.logger.info("Following client-side redirect", {
+              redirectUrl: error.url,
+              kind: error.kind,
+            });
+            meta.clientRedirects = [
+              ...(meta.clientRedirects ?? []),
+              { url: error.url, kind: error.kind },
+            ];
+            meta.rewrittenUrl = rewriteUrl(error.url) ?? error.url;
           } else if (
             error instanceof PDFAntibotError &&
             meta.internalOptions.forceEngine === undefined
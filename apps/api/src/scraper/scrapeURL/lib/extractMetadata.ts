@@ -7,7 +7,10 @@ async function extractMetadataRust(
   meta: Meta,
   html: string,
 ): Promise<Partial<Document["metadata"]>> {
-  const { favicon: _favicon, ...fromRust } = await _extractMetadata(html);
+  const { favicon: _favicon, ...fromRust } = await _extractMetadata(
+    html,
+    meta.rewrittenUrl ?? meta.url,
+  );
 
   let favicon: string | undefined = undefined;
 
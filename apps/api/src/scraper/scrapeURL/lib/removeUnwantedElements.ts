@@ -2,7 +2,11 @@
 
 import { AnyNode, Cheerio, load } from "cheerio"; // rustified
 import { ScrapeOptions } from "../../../controllers/v2/types";
-import { transformHtml } from "@mendable/firecrawl-rs";
+import {
+  extractBaseHref,
+  extractMainContent,
+  transformHtml,
+} from "@mendable/firecrawl-rs";
 import { logger } from "../../../lib/logger";
 import { queryOMCESignatures } from "../../../services/index";
 
@@ -89,6 +93,27 @@ export const htmlTransform = async (
     }
   }
 
+  // Readability scoring picks the article out of the page, and transformHtml
+  // then strips whatever boilerplate is left inside it. includeTags selects
+  // from the whole page, so it skips this step. The extracted fragment has no
+  // <head>, so links resolve against the page's <base href> from here on.
+  if (
+    scrapeOptions.onlyMainContent &&
+    !(scrapeOptions.includeTags ?? []).some(x => x.trim().length !== 0)
+  ) {
+    try {
+      const baseUrl = await extractBaseHref(html, url);
+      html = await extractMainContent(html);
+      url = baseUrl;
+    } catch (error) {
+      logger.warn("Failed to extract main content, using the full page", {
+        error,
+        module: "scrapeURL",
+        method: "htmlTransform",
+      });
+    }
+  }
+
   try {
     return await transformHtml({
       html,
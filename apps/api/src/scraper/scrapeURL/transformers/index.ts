@@ -4,6 +4,7 @@ import { Document } from "../../../controllers/v2/types";
 import { htmlTransform } from "../lib/removeUnwantedElements";
 import { extractLinks } from "../lib/extractLinks";
 import { extractImages } from "../lib/extractImages";
+import { extractCodeBlocks } from "@mendable/firecrawl-rs";
 import { extractMetadata } from "../lib/extractMetadata";
 import { performLLMExtract, performSummary } from "./llmExtract";
 import { uploadScreenshot } from "./uploadScreenshot";
@@ -112,6 +113,7 @@ async function deriveMarkdownFromHTML(
   document.markdown = await parseMarkdown(document.html, {
     logger: meta.logger,
     requestId,
+    markdownOptions: meta.options.markdownOptions,
   });
 
   if (
@@ -134,6 +136,7 @@ async function deriveMarkdownFromHTML(
     document.markdown = await parseMarkdown(document.html, {
       logger: meta.logger,
       requestId,
+      markdownOptions: meta.options.markdownOptions,
     });
 
     meta.logger.info("Fallback to full content extraction completed", {
@@ -192,6 +195,24 @@ async function deriveImagesFromHTML(
   return document;
 }
 
+async function deriveCodeBlocksFromHTML(
+  meta: Meta,
+  document: Document,
+): Promise<Document> {
+  // Only derive if the formats has codeBlocks
+  if (hasFormatOfType(meta.options.formats, "codeBlocks")) {
+    if (document.html === undefined) {
+      throw new Error(
+        "html is undefined -- this transformer is being called out of order",
+      );
+    }
+
+    document.codeBlocks = await extractCodeBlocks(document.html);
+  }
+
+  return document;
+}
+
 async function deriveBrandingFromActions(
   meta: Meta,
   document: Document,
@@ -238,6 +259,7 @@ function coerceFieldsToFormats(meta: Meta, document: Document): Document {
   const hasHtml = hasFormatOfType(meta.options.formats, "html");
   const hasLinks = hasFormatOfType(meta.options.formats, "links");
   const hasImages = hasFormatOfType(meta.options.formats, "images");
+  const hasCodeBlocks = hasFormatOfType(meta.options.formats, "codeBlocks");
   const hasChangeTracking = hasFormatOfType(
     meta.options.formats,
     "changeTracking",
@@ -306,6 +328,17 @@ function coerceFieldsToFormats(meta: Meta, document: Document): Document {
     );
   }
 
+  if (!hasCodeBlocks && document.codeBlocks !== undefined) {
+    meta.logger.warn(
+      "Removed codeBlocks from Document because it wasn't in formats -- this is wasteful and indicates a bug.",
+    );
+    delete document.codeBlocks;
+  } else if (hasCodeBlocks && document.codeBlocks === undefined) {
+    meta.logger.warn(
+      "Request had format: codeBlocks, but there was no codeBlocks field in the result.",
+    );
+  }
+
   // Handle v1 backward compatibility - don't delete fields based on v1OriginalFormat
   const shouldKeepExtract = meta.internalOptions.v1OriginalFormat === "extract";
   const shouldKeepJson = meta.internalOptions.v1OriginalFormat === "json";
@@ -431,6 +464,7 @@ const transformerStack: Transformer[] = [
   deriveMarkdownFromHTML,
   deriveLinksFromHTML,
   deriveImagesFromHTML,
+  deriveCodeBlocksFromHTML,
   deriveBrandingFromActions,
   deriveMetadataFromRawHTML,
   uploadScreenshot,
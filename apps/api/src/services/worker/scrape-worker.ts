@@ -19,6 +19,7 @@ import {
   addCrawlJobDone,
   crawlToCrawler,
   recordRobotsBlocked,
+  recordDenialCounts,
   finishCrawlKickoff,
   generateURLPermutations,
   getCrawl,
@@ -372,6 +373,10 @@ async function processJob(job: NuQJob<ScrapeJobSingleUrls>) {
                 await recordRobotsBlocked(job.data.crawl_id, url);
               }
             }
+            await recordDenialCounts(
+              job.data.crawl_id,
+              links.denialCounts ?? {},
+            );
 
             for (const link of links.links) {
               if (await lockURL(job.data.crawl_id, sc, link)) {
@@ -754,33 +759,51 @@ async function kickoffGetIndexLinks(
   return validIndexLinks;
 }
 
-async function addKickoffSitemapJob(
+// Child sitemaps of a sitemap index are fetched inside the kickoff_sitemap job,
+// this many at a time, instead of each waiting for its own job.
+const KICKOFF_SITEMAP_CONCURRENCY = 5;
+// Cap on the URLs queued from a single sitemap, applied after filtering.
+const KICKOFF_SITEMAP_MAX_URLS = 10000;
+
+async function lockKickoffSitemap(
+  crawlId: string,
   sitemapUrl: string,
-  sourceJob: NuQJob<ScrapeJobKickoff | ScrapeJobKickoffSitemap>,
-  sc: StoredCrawl,
   logger: Logger,
-) {
+): Promise<boolean> {
   // TEMP: max 20 sitemaps per crawl
   if (
-    (await redisEvictConnection.scard(
-      "crawl:" + sourceJob.data.crawl_id + ":sitemaps",
-    )) >= 20
+    (await redisEvictConnection.scard("crawl:" + crawlId + ":sitemaps")) >= 20
   ) {
     logger.debug("Sitemap limit reached, skipping...", { sitemap: sitemapUrl });
-    return;
+    return false;
   }
 
   const sitemapLocked =
     (await redisEvictConnection.sadd(
-      "crawl:" + sourceJob.data.crawl_id + ":sitemaps",
+      "crawl:" + crawlId + ":sitemaps",
       sitemapUrl,
     )) === 1;
   await redisEvictConnection.expire(
-    "crawl:" + sourceJob.data.crawl_id + ":sitemaps",
+    "crawl:" + crawlId + ":sitemaps",
     24 * 60 * 60,
   );
   if (!sitemapLocked) {
     logger.debug("Sitemap already hit, skipping...", { sitemap: sitemapUrl });
+    return false;
+  }
+
+  return true;
+}
+
+async function addKickoffSitemapJob(
+  sitemapUrl: string,
+  sourceJob: NuQJob<ScrapeJobKickoff | ScrapeJobKickoffSitemap>,
+  sc: StoredCrawl,
+  logger: Logger,
+) {
+  if (
+    !(await lockKickoffSitemap(sourceJob.data.crawl_id, sitemapUrl, logger))
+  ) {
     return;
   }
 
@@ -977,6 +1000,88 @@ async function processKickoffJob(job: NuQJob<ScrapeJobKickoff>) {
   }
 }
 
+async function queueSitemapURLs(
+  job: NuQJob<ScrapeJobKickoffSitemap>,
+  sc: StoredCrawl,
+  crawler: WebCrawler,
+  sitemapUrl: string,
+  logger: Logger,
+): Promise<URL[]> {
+  const results = await scrapeSitemap({
+    url: sitemapUrl,
+    maxAge: 48 * 60 * 60 * 1000,
+    zeroDataRetention: job.data.zeroDataRetention ?? false,
+    location: job.data.location,
+    crawlId: job.data.crawl_id,
+    logger,
+    isPreCrawl: sc.internalOptions?.isPreCrawl ?? false,
+  });
+
+  const filterResult = await crawler.filterLinks(
+    results.urls.map(x => x.href),
+    Infinity,
+    sc.crawlerOptions.maxDepth ?? 10,
+    false,
+  );
+  await recordDenialCounts(job.data.crawl_id, filterResult.denialCounts ?? {});
+
+  // processSitemap orders URLs by priority and lastmod, so the cap keeps the
+  // pages the site considers most important.
+  const passingURLs = filterResult.links.slice(0, KICKOFF_SITEMAP_MAX_URLS);
+
+  if (passingURLs.length > 0) {
+    logger.debug("Using urls of length " + passingURLs.length, {
+      urlsLength: passingURLs.length,
+      sitemapUrl,
+    });
+
+    const jobPriority = await getJobPriority({
+      team_id: job.data.team_id,
+      basePriority: 21,
+    });
+
+    const jobs = passingURLs.map(url => ({
+      data: {
+        url: url,
+        mode: "single_urls" as const,
+        team_id: job.data.team_id,
+        crawlerOptions: sc.crawlerOptions,
+        scrapeOptions: sc.scrapeOptions,
+        internalOptions: sc.internalOptions,
+        origin: job.data.origin,
+        integration: job.data.integration,
+        crawl_id: job.data.crawl_id,
+        requestId: job.data.requestId,
+        sitemapped: true,
+        webhook: job.data.webhook,
+        v1: job.data.v1,
+        zeroDataRetention:
+          job.data.zeroDataRetention || (sc.zeroDataRetention ?? false),
+        apiKeyId: job.data.apiKeyId,
+      } satisfies ScrapeJobSingleUrls,
+      jobId: uuidv7(),
+      priority: jobPriority,
+    }));
+
+    const urls = await lockURLsIndividually(
+      job.data.crawl_id,
+      sc,
+      jobs.map(x => ({ id: x.jobId, url: x.data.url })),
+    );
+    const winningIds = new Set(urls.map(x => x.id));
+    await addCrawlJobs(
+      job.data.crawl_id,
+      urls.map(x => x.id),
+      logger,
+    );
+    await addScrapeJobs(jobs.filter(x => winningIds.has(x.jobId)));
+
+    logger.debug("Done queueing jobs!", { sitemapUrl });
+  }
+
+  return results.sitemaps;
+}
+
 async function processKickoffSitemapJob(job: NuQJob<ScrapeJobKickoffSitemap>) {
   const logger = _logger.child({
     module: "queue-worker",
@@ -1001,84 +1106,63 @@ async function processKickoffSitemapJob(job: NuQJob<ScrapeJobKickoffSitemap>) {
       (await getACUCTeam(job.data.team_id))?.flags ?? null,
     );
 
-    const results = await scrapeSitemap({
-      url: job.data.sitemapUrl,
-      maxAge: 48 * 60 * 60 * 1000,
-      zeroDataRetention: job.data.zeroDataRetention ?? false,
-      location: job.data.location,
-      crawlId: job.data.crawl_id,
+    const sitemaps = await queueSitemapURLs(
+      job,
+      sc,
+      crawler,
+      job.data.sitemapUrl,
       logger,
-      isPreCrawl: sc.internalOptions?.isPreCrawl ?? false,
-    });
-
-    const passingURLs = (
-      await crawler.filterLinks(
-        results.urls.map(x => x.href),
-        Infinity,
-        sc.crawlerOptions.maxDepth ?? 10,
-        false,
-      )
-    ).links;
+    );
 
-    if (passingURLs.length > 0) {
-      logger.debug("Using urls of length " + passingURLs.length, {
-        urlsLength: passingURLs.length,
+    if (sitemaps.length > 0) {
+      logger.debug("Using sitemaps of length " + sitemaps.length, {
+        sitemapsLength: sitemaps.length,
       });
 
-      const jobPriority = await getJobPriority({
-        team_id: job.data.team_id,
-        basePriority: 21,
-      });
+      const lockedSitemaps: URL[] = [];
+      for (const sitemap of sitemaps) {
+        if (await lockKickoffSitemap(job.data.crawl_id, sitemap.href, logger)) {
+          lockedSitemaps.push(sitemap);
+        }
+      }
 
-      const jobs = passingURLs.map(url => ({
-        data: {
-          url: url,
-          mode: "single_urls" as const,
-          team_id: job.data.team_id,
-          crawlerOptions: sc.crawlerOptions,
-          scrapeOptions: sc.scrapeOptions,
-          internalOptions: sc.internalOptions,
-          origin: job.data.origin,
-          integration: job.data.integration,
-          crawl_id: job.data.crawl_id,
-          requestId: job.data.requestId,
-          sitemapped: true,
-          webhook: job.data.webhook,
-          v1: job.data.v1,
-          zeroDataRetention:
-            job.data.zeroDataRetention || (sc.zeroDataRetention ?? false),
-          apiKeyId: job.data.apiKeyId,
-        } satisfies ScrapeJobSingleUrls,
-        jobId: uuidv7(),
-        priority: jobPriority,
-      }));
-
-      const urls = await lockURLsIndividually(
-        job.data.crawl_id,
-        sc,
-        jobs.map(x => ({ id: x.jobId, url: x.data.url })),
+      // Nested indexes are rare, so sitemaps found one level down go back on
+      // the queue as their own jobs.
+      const nestedSitemaps: URL[] = [];
+      const workers = Math.min(
+        KICKOFF_SITEMAP_CONCURRENCY,
+        lockedSitemaps.length,
       );
-      const winningIds = new Set(urls.map(x => x.id));
-      await addCrawlJobs(
-        job.data.crawl_id,
-        urls.map(x => x.id),
-        logger,
+      let next = 0;
+      await Promise.all(
+        Array.from({ length: workers }, async () => {
+          while (next < lockedSitemaps.length) {
+            const sitemap = lockedSitemaps[next++];
+            try {
+              nestedSitemaps.push(
+                ...(await queueSitemapURLs(
+                  job,
+                  sc,
+                  crawler,
+                  sitemap.href,
+                  logger,
+                )),
+              );
+            } catch (error) {
+              logger.warn("Failed to process child sitemap", {
+                error,
+                sitemapUrl: sitemap.href,
+              });
+            }
+          }
+        }),
       );
-      await addScrapeJobs(jobs.filter(x => winningIds.has(x.jobId)));
-
-      logger.debug("Done queueing jobs!");
-    }
-
-    if (results.sitemaps.length > 0) {
-      logger.debug("Using sitemaps of length " + results.sitemaps.length, {
-        sitemapsLength: results.sitemaps.length,
-      });
 
-      for (const sitemap of results.sitemaps) {
+      for (const sitemap of nestedSitemaps) {
         await addKickoffSitemapJob(sitemap.href, job, sc, logger);
       }
 
-      logger.debug("Done queueing sitemap jobs!");
+      logger.debug("Done processing sitemaps!");
     }
     return { success: true };
   } catch (error) {
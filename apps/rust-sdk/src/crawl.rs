@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::RangeInclusive;
 
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -183,6 +185,9 @@ pub struct CrawlOptions {
 
     pub delay: Option<u32>,
 
+    /// Maximum number of pages of this crawl that may be scraped concurrently. Capped by the team's concurrency limit.
+    pub max_concurrency: Option<u32>,
+
     /// When using `FirecrawlApp::crawl_url`, this is how often the status of the job should be checked, in milliseconds. (default: `2000`)
     #[serde(skip)]
     pub poll_interval: Option<u64>,
@@ -240,6 +245,124 @@ pub struct CrawlStatus {
     pub data: Vec<Document>,
 }
 
+/// Keys that `CrawlStatus::sort_by` can order crawled documents by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrawlSortKey {
+    /// Order by the source URL of the page.
+    Url,
+
+    /// Order by the HTTP status code of the page, then by source URL.
+    StatusCode,
+}
+
+impl CrawlStatus {
+    /// Sorts the crawled documents in place.
+    pub fn sort_by(&mut self, key: CrawlSortKey) {
+        match key {
+            CrawlSortKey::Url => self
+                .data
+                .sort_by(|a, b| a.metadata.source_url.cmp(&b.metadata.source_url)),
+            CrawlSortKey::StatusCode => self.data.sort_by(|a, b| {
+                a.metadata
+                    .status_code
+                    .cmp(&b.metadata.status_code)
+                    .then_with(|| a.metadata.source_url.cmp(&b.metadata.source_url))
+            }),
+        }
+    }
+
+    /// Groups the crawled documents by the first `depth` segments of their URL path.
+    ///
+    /// For example, with a `depth` of `1`, `https://example.com/blog/post-1` is grouped under `/blog`.
+    /// Documents whose source URL can not be parsed are grouped under an empty key.
+    pub fn group_by_path(&self, depth: usize) -> BTreeMap<String, Vec<&Document>> {
+        let mut groups: BTreeMap<String, Vec<&Document>> = BTreeMap::new();
+
+        for document in &self.data {
+            let prefix = reqwest::Url::parse(&document.metadata.source_url)
+                .map(|url| {
+                    let segments: Vec<&str> = url
+                        .path_segments()
+                        .map(|segments| segments.filter(|s| !s.is_empty()).take(depth).collect())
+                        .unwrap_or_default();
+                    format!("/{}", segments.join("/"))
+                })
+                .unwrap_or_default();
+
+            groups.entry(prefix).or_default().push(document);
+        }
+
+        groups
+    }
+
+    /// Returns the crawled documents that match every condition set on `filter`.
+    ///
+    /// The filter runs client-side over the documents already held in `data`: it does not change
+    /// what the API crawls or bills for, and when the status is paginated (`next` is set) only the
+    /// documents of this page are considered.
+    pub fn filter(&self, filter: &CrawlResultFilter) -> Result<Vec<&Document>, FirecrawlError> {
+        let url_pattern = filter
+            .url_pattern
+            .as_ref()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    FirecrawlError::Missuse(format!("Invalid URL pattern {:?}: {}", pattern, e))
+                })
+            })
+            .transpose()?;
+
+        Ok(self
+            .data
+            .iter()
+            .filter(|document| {
+                filter
+                    .status_codes
+                    .as_ref()
+                    .is_none_or(|codes| codes.contains(&document.metadata.status_code))
+            })
+            .filter(|document| {
+                filter.content_type.as_ref().is_none_or(|content_type| {
+                    document
+                        .metadata
+                        .content_type
+                        .as_ref()
+                        .is_some_and(|x| x.starts_with(content_type.as_str()))
+                })
+            })
+            .filter(|document| {
+                url_pattern
+                    .as_ref()
+                    .is_none_or(|r| r.is_match(&document.metadata.source_url))
+            })
+            .filter(|document| {
+                !filter.changed_only
+                    || document
+                        .change_tracking
+                        .as_ref()
+                        .and_then(|x| x["changeStatus"].as_str())
+                        .is_some_and(|x| x == "new" || x == "changed")
+            })
+            .collect())
+    }
+}
+
+/// Conditions used by `CrawlStatus::filter` to select a subset of crawled documents, applied
+/// client-side after the crawl results have been fetched.
+#[derive(Debug, Default, Clone)]
+pub struct CrawlResultFilter {
+    /// Only keep documents whose HTTP status code falls in this range, e.g. `400..=599` for failed pages.
+    pub status_codes: Option<RangeInclusive<u16>>,
+
+    /// Only keep documents whose content type starts with this value, e.g. `application/pdf`.
+    pub content_type: Option<String>,
+
+    /// Only keep documents whose source URL matches this RegEx pattern.
+    pub url_pattern: Option<String>,
+
+    /// Only keep documents that change tracking reported as new or changed.
+    pub changed_only: bool,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CrawlError {
@@ -255,6 +378,9 @@ pub struct CrawlErrorsResponse {
     pub errors: Vec<CrawlError>,
     #[serde(rename = "robotsBlocked")]
     pub robots_blocked: Vec<String>,
+    /// Number of discovered URLs the crawler skipped, per denial reason (e.g. `ROBOTS_TXT`).
+    #[serde(default)]
+    pub denial_counts: HashMap<String, u32>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -380,6 +506,28 @@ impl FirecrawlApp {
         Ok(status)
     }
 
+    /// Fetches a single page of a crawl's results without following `next`, so it can be used to pull results incrementally while the crawl is still running.
+    ///
+    /// Pass `None` as `cursor` for the first pull, then pass the `next` value of the previously returned page to only receive results added since. The API keeps returning a `next` cursor until the crawl is completed and all results were handed out, so a `None` cursor in the response means there is nothing left to pull.
+    pub async fn check_crawl_status_page(
+        &self,
+        id: impl AsRef<str>,
+        cursor: Option<&str>,
+    ) -> Result<CrawlStatus, FirecrawlError> {
+        match cursor {
+            Some(next) => self.check_crawl_status_next(next).await,
+            None => {
+                self.check_crawl_status_next(format!(
+                    "{}{}/crawl/{}",
+                    self.api_url,
+                    API_VERSION,
+                    id.as_ref()
+                ))
+                .await
+            }
+        }
+    }
+
     async fn monitor_job_status(
         &self,
         id: &str,
@@ -470,6 +618,220 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    fn crawl_status_with(pages: &[(&str, u16)]) -> CrawlStatus {
+        CrawlStatus {
+            status: CrawlStatusTypes::Completed,
+            total: pages.len() as u32,
+            completed: pages.len() as u32,
+            credits_used: pages.len() as u32,
+            expires_at: "2025-01-01T00:00:00Z".to_string(),
+            next: None,
+            data: pages
+                .iter()
+                .map(|(url, status_code)| Document {
+                    metadata: crate::document::DocumentMetadata {
+                        source_url: url.to_string(),
+                        status_code: *status_code,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_crawl_status_sort_and_group() {
+        let mut status = crawl_status_with(&[
+            ("https://example.com/docs/b", 200),
+            ("https://example.com/blog/a", 404),
+            ("https://example.com/docs/a", 200),
+            ("https://example.com/", 500),
+        ]);
+
+        status.sort_by(CrawlSortKey::Url);
+        let urls: Vec<&str> = status
+            .data
+            .iter()
+            .map(|d| d.metadata.source_url.as_str())
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/",
+                "https://example.com/blog/a",
+                "https://example.com/docs/a",
+                "https://example.com/docs/b",
+            ]
+        );
+
+        status.sort_by(CrawlSortKey::StatusCode);
+        assert_eq!(
+            status.data[0].metadata.source_url,
+            "https://example.com/docs/a"
+        );
+        assert_eq!(status.data[3].metadata.status_code, 500);
+
+        let groups = status.group_by_path(1);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups["/docs"].len(), 2);
+        assert_eq!(groups["/blog"].len(), 1);
+        assert_eq!(groups["/"].len(), 1);
+    }
+
+    #[test]
+    fn test_crawl_status_filter() {
+        let mut status = crawl_status_with(&[
+            ("https://example.com/report.pdf", 200),
+            ("https://example.com/docs/a", 200),
+            ("https://example.com/docs/missing", 404),
+        ]);
+        status.data[0].metadata.content_type = Some("application/pdf".to_string());
+        status.data[1].metadata.content_type = Some("text/html; charset=utf-8".to_string());
+        status.data[1].change_tracking = Some(json!({ "changeStatus": "changed" }));
+        status.data[2].change_tracking = Some(json!({ "changeStatus": "same" }));
+
+        let failed = status
+            .filter(&CrawlResultFilter {
+                status_codes: Some(400..=599),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            failed[0].metadata.source_url,
+            "https://example.com/docs/missing"
+        );
+
+        let pdfs = status
+            .filter(&CrawlResultFilter {
+                content_type: Some("application/pdf".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(pdfs.len(), 1);
+
+        let changed_docs = status
+            .filter(&CrawlResultFilter {
+                url_pattern: Some("/docs/".to_string()),
+                changed_only: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(changed_docs.len(), 1);
+        assert_eq!(
+            changed_docs[0].metadata.source_url,
+            "https://example.com/docs/a"
+        );
+
+        let invalid = status.filter(&CrawlResultFilter {
+            url_pattern: Some("(".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(invalid, Err(FirecrawlError::Missuse(_))));
+    }
+
+    #[tokio::test]
+    async fn test_check_crawl_status_page_with_mock() {
+        let mut server = mockito::Server::new_async().await;
+        let next = format!("{}/v1/crawl/test-crawl-id?skip=1", server.url());
+
+        let first_mock = server
+            .mock("GET", "/v1/crawl/test-crawl-id")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "status": "scraping",
+                    "total": 3,
+                    "completed": 1,
+                    "creditsUsed": 1,
+                    "expiresAt": "2025-01-01T00:00:00Z",
+                    "next": next,
+                    "data": [{ "metadata": { "sourceURL": "https://example.com/a", "statusCode": 200 } }]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let next_mock = server
+            .mock("GET", "/v1/crawl/test-crawl-id")
+            .match_query(mockito::Matcher::UrlEncoded("skip".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "status": "completed",
+                    "total": 2,
+                    "completed": 2,
+                    "creditsUsed": 2,
+                    "expiresAt": "2025-01-01T00:00:00Z",
+                    "data": [{ "metadata": { "sourceURL": "https://example.com/b", "statusCode": 200 } }]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let app = FirecrawlApp::new_selfhosted(server.url(), Some("test_key")).unwrap();
+
+        let first = app
+            .check_crawl_status_page("test-crawl-id", None)
+            .await
+            .unwrap();
+        assert_eq!(first.status, CrawlStatusTypes::Scraping);
+        assert_eq!(first.data.len(), 1);
+        assert_eq!(first.next.as_deref(), Some(next.as_str()));
+
+        let second = app
+            .check_crawl_status_page("test-crawl-id", first.next.as_deref())
+            .await
+            .unwrap();
+        assert_eq!(second.data.len(), 1);
+        assert_eq!(second.data[0].metadata.source_url, "https://example.com/b");
+        assert!(second.next.is_none());
+
+        first_mock.assert();
+        next_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_crawl_url_async_sends_max_concurrency() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v1/crawl")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "url": "https://example.com",
+                "maxConcurrency": 1
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "id": "test-crawl-id",
+                    "url": "https://api.firecrawl.dev/v1/crawl/test-crawl-id"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let app = FirecrawlApp::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let options = CrawlOptions {
+            max_concurrency: Some(1),
+            ..Default::default()
+        };
+
+        let response = app
+            .crawl_url_async("https://example.com", Some(options))
+            .await
+            .unwrap();
+        assert_eq!(response.id, "test-crawl-id");
+        mock.assert();
+    }
+
     #[tokio::test]
     #[ignore = "Makes real network request"]
     async fn test_real_cancel_crawl() {
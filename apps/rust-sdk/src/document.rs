@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -8,8 +10,11 @@ pub struct DocumentMetadata {
     // firecrawl specific
     #[serde(rename = "sourceURL")]
     pub source_url: String,
+    /// The URL the page was finally loaded from, after following redirects.
+    pub url: Option<String>,
     pub status_code: u16,
     pub error: Option<String>,
+    pub content_type: Option<String>,
 
     // basic meta tags
     pub title: Option<String>,
@@ -47,6 +52,19 @@ pub struct DocumentMetadata {
     pub dc_date: Option<String>,
     pub dc_date_created: Option<String>,
     pub dcterms_created: Option<String>,
+
+    // response diagnostics
+    pub scrape_id: Option<String>,
+    pub num_pages: Option<u32>,
+    pub timezone: Option<String>,
+    pub proxy_used: Option<String>,
+    pub cache_state: Option<String>,
+    pub cached_at: Option<String>,
+    pub credits_used: Option<u32>,
+
+    /// Any other metadata returned by the API, such as meta tags without a dedicated field.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -79,7 +97,90 @@ pub struct Document {
     /// The metadata from the page.
     pub metadata: DocumentMetadata,
 
+    /// Change tracking information, present if change tracking was requested for the page.
+    pub change_tracking: Option<Value>,
+
+    /// Outputs of the actions in `ScrapeOptions.actions`, in the order they ran.
+    pub actions: Option<ActionResults>,
+
     /// Can be present if `ScrapeFormats::Extract` is present in `ScrapeOptions.formats`.
     /// The warning message will contain any errors encountered during the extraction.
     pub warning: Option<String>,
 }
+
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionResults {
+    /// Screenshot URLs, one per `Action::Screenshot`.
+    pub screenshots: Option<Vec<String>>,
+
+    /// Page snapshots, one per `Action::Scrape`.
+    pub scrapes: Option<Vec<ActionScrape>>,
+
+    /// Return values, one per `Action::ExecuteJavascript`.
+    pub javascript_returns: Option<Vec<JavascriptReturn>>,
+
+    /// PDF URLs, one per `Action::Pdf`.
+    pub pdfs: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionScrape {
+    pub url: String,
+    pub html: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct JavascriptReturn {
+    /// The JavaScript type of the returned value, e.g. `"string"` or `"object"`.
+    #[serde(rename = "type")]
+    pub value_type: String,
+    pub value: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_document_metadata_extra() {
+        let metadata: DocumentMetadata = serde_json::from_value(json!({
+            "sourceURL": "https://example.com",
+            "statusCode": 200,
+            "title": "Example Domain",
+            "twitter:card": "summary",
+            "generator": ["WordPress 6.5", "Elementor"]
+        }))
+        .unwrap();
+
+        assert_eq!(metadata.title.as_deref(), Some("Example Domain"));
+        assert_eq!(metadata.extra.len(), 2);
+        assert_eq!(metadata.extra["twitter:card"], json!("summary"));
+        assert_eq!(
+            metadata.extra["generator"],
+            json!(["WordPress 6.5", "Elementor"])
+        );
+
+        let serialized = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(serialized["twitter:card"], json!("summary"));
+        assert!(serialized.get("extra").is_none());
+    }
+
+    #[test]
+    fn test_document_metadata_url() {
+        let metadata: DocumentMetadata = serde_json::from_value(json!({
+            "sourceURL": "https://example.com",
+            "url": "https://www.example.com/",
+            "statusCode": 200
+        }))
+        .unwrap();
+
+        assert_eq!(metadata.source_url, "https://example.com");
+        assert_eq!(metadata.url.as_deref(), Some("https://www.example.com/"));
+        assert!(metadata.extra.is_empty());
+    }
+}
@@ -16,6 +16,9 @@ pub struct FirecrawlAPIError {
 
     /// Additional details of this error. Schema depends on the error itself.
     pub details: Option<Value>,
+
+    /// Machine-readable error code (e.g. `SCRAPE_TIMEOUT`), if the API provided one.
+    pub code: Option<String>,
 }
 
 impl Display for FirecrawlAPIError {
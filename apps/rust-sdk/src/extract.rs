@@ -198,6 +198,7 @@ impl FirecrawlApp {
                     success: false,
                     error: "Either prompt or schema must be provided".to_string(),
                     details: None,
+                    code: None,
                 },
             ));
         }
@@ -232,6 +233,7 @@ impl FirecrawlApp {
                     success: false,
                     error: e.to_string(),
                     details: None,
+                    code: None,
                 },
             )
         })?;
@@ -266,6 +268,7 @@ impl FirecrawlApp {
                     success: false,
                     error: "Either URLs or prompt must be provided".to_string(),
                     details: None,
+                    code: None,
                 },
             ));
         }
@@ -278,6 +281,7 @@ impl FirecrawlApp {
                     success: false,
                     error: "Either prompt or schema must be provided".to_string(),
                     details: None,
+                    code: None,
                 },
             ));
         }
@@ -358,6 +362,7 @@ impl FirecrawlApp {
                             success: false,
                             error: error_msg,
                             details: None,
+                            code: None,
                         },
                     ));
                 }
@@ -368,6 +373,7 @@ impl FirecrawlApp {
                             success: false,
                             error: format!("Unexpected status: {}", status_data.status),
                             details: None,
+                            code: None,
                         },
                     ));
                 }
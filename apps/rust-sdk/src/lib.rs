@@ -81,6 +81,7 @@ impl FirecrawlApp {
                     success: false,
                     error: "API key is required for cloud service".to_string(),
                     details: None,
+                    code: None,
                 },
             ));
         }
@@ -117,6 +117,7 @@ impl FirecrawlApp {
                     success: false,
                     error: "URL must be provided".to_string(),
                     details: None,
+                    code: None,
                 },
             ));
         }
@@ -204,6 +205,7 @@ impl FirecrawlApp {
                             success: false,
                             error: error_msg,
                             details: None,
+                            code: None,
                         },
                     ));
                 }
@@ -214,6 +216,7 @@ impl FirecrawlApp {
                             success: false,
                             error: format!("Unexpected status: {}", status_data.status),
                             details: None,
+                            code: None,
                         },
                     ));
                 }
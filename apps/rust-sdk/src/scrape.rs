@@ -3,7 +3,12 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{document::Document, FirecrawlApp, FirecrawlError, API_VERSION};
+use crate::{
+    document::Document,
+    map::Location,
+    v2::{PdfFormat, ProxyType, ScrollDirection},
+    FirecrawlApp, FirecrawlError, API_VERSION,
+};
 
 #[derive(Deserialize, Serialize, Clone, Copy, Debug)]
 pub enum ScrapeFormats {
@@ -42,6 +47,56 @@ pub enum ScrapeFormats {
     Json,
 }
 
+/// A browser action to perform on the page before scraping it.
+///
+/// Outputs of `Screenshot`, `Scrape`, `ExecuteJavascript` and `Pdf` actions are returned in `Document.actions`.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Action {
+    /// Wait for a number of milliseconds, or for an element matching `selector` to appear. Exactly one of the two must be set.
+    Wait {
+        milliseconds: Option<u32>,
+        selector: Option<String>,
+    },
+
+    /// Click the first element matching `selector`, or all of them if `all` is set.
+    Click { selector: String, all: Option<bool> },
+
+    /// Take a screenshot of the page at this point.
+    #[serde(rename_all = "camelCase")]
+    Screenshot {
+        full_page: Option<bool>,
+        quality: Option<u8>,
+    },
+
+    /// Type text into the focused input.
+    Write { text: String },
+
+    /// Press a keyboard key.
+    Press { key: String },
+
+    /// Scroll the page, or the element matching `selector`.
+    Scroll {
+        direction: Option<ScrollDirection>,
+        selector: Option<String>,
+    },
+
+    /// Capture the page's HTML at this point.
+    Scrape,
+
+    /// Run JavaScript in the page. The script's return value is captured.
+    #[serde(rename = "executeJavascript")]
+    ExecuteJavascript { script: String },
+
+    /// Render the page as a PDF.
+    Pdf {
+        landscape: Option<bool>,
+        scale: Option<f32>,
+        format: Option<PdfFormat>,
+    },
+}
+
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentOptionsJson {
@@ -104,11 +159,23 @@ pub struct ScrapeOptions {
     // Timeout before returning an error, in milliseconds. (default: `60000`)
     pub timeout: Option<u32>,
 
+    /// Render the page with a mobile viewport and user agent, to capture mobile-only content. (default: `false`)
+    pub mobile: Option<bool>,
+
+    /// Country and preferred languages to scrape from. Sets the proxy location and the browser's locale, for region-gated content.
+    pub location: Option<Location>,
+
     /// JSON extraction options, to be used in conjunction with `ScrapeFormats::Json`.
     pub json_options: Option<JsonOptions>,
 
     /// Agent options for smart scrape.
     pub agent: Option<AgentOptions>,
+
+    /// Browser actions to perform before grabbing the content.
+    pub actions: Option<Vec<Action>>,
+
+    /// Which proxy to route the request through. `ProxyType::Auto` retries with a stealth proxy if the basic one is blocked. (default: `ProxyType::Basic`)
+    pub proxy: Option<ProxyType>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -161,3 +228,67 @@ impl FirecrawlApp {
         Ok(response.data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_action_serialization() {
+        let actions = vec![
+            Action::Wait {
+                milliseconds: Some(500),
+                selector: None,
+            },
+            Action::Click {
+                selector: "#more".to_string(),
+                all: None,
+            },
+            Action::Screenshot {
+                full_page: Some(true),
+                quality: Some(80),
+            },
+            Action::Scroll {
+                direction: None,
+                selector: None,
+            },
+            Action::Scroll {
+                direction: Some(ScrollDirection::Up),
+                selector: Some("#feed".to_string()),
+            },
+            Action::Scrape,
+            Action::ExecuteJavascript {
+                script: "document.title".to_string(),
+            },
+            Action::Pdf {
+                landscape: Some(true),
+                scale: None,
+                format: Some(PdfFormat::A4),
+            },
+        ];
+
+        assert_eq!(
+            serde_json::to_value(&actions).unwrap(),
+            json!([
+                { "type": "wait", "milliseconds": 500 },
+                { "type": "click", "selector": "#more" },
+                { "type": "screenshot", "fullPage": true, "quality": 80 },
+                { "type": "scroll" },
+                { "type": "scroll", "direction": "up", "selector": "#feed" },
+                { "type": "scrape" },
+                { "type": "executeJavascript", "script": "document.title" },
+                { "type": "pdf", "landscape": true, "format": "A4" }
+            ])
+        );
+
+        let scroll: Action = serde_json::from_value(json!({ "type": "scroll" })).unwrap();
+        assert!(matches!(
+            scroll,
+            Action::Scroll {
+                direction: None,
+                selector: None
+            }
+        ));
+    }
+}
@@ -99,6 +99,7 @@ impl Client {
                             success: false,
                             error: "API key is required for cloud service".to_string(),
                             details: None,
+                            code: None,
                         },
                     ));
                 }
@@ -109,6 +110,7 @@ impl Client {
                             success: false,
                             error: "API key cannot be empty for cloud service".to_string(),
                             details: None,
+                            code: None,
                         },
                     ));
                 }
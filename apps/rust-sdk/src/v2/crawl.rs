@@ -111,6 +111,17 @@ pub struct CrawlJob {
     pub data: Vec<Document>,
 }
 
+/// Pagination parameters for fetching a single page of crawl results.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginationOptions {
+    /// Number of results to skip from the start of the result list.
+    pub skip: Option<u32>,
+    /// Maximum number of results to return in this page.
+    pub limit: Option<u32>,
+}
+
 /// Response from canceling a crawl.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -235,6 +246,72 @@ impl Client {
         Ok(status)
     }
 
+    /// Gets a single page of a crawl job's results without auto-paginating.
+    ///
+    /// Use this for large crawls where loading every document at once is too expensive.
+    /// The returned `next` URL carries the offset of the following page and can be passed
+    /// to [`Client::get_crawl_status_page_next`].
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The crawl job ID.
+    /// * `pagination` - Optional `skip` and `limit` for the page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use firecrawl::v2::{Client, PaginationOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("your-api-key")?;
+    ///
+    ///     let mut page = client
+    ///         .get_crawl_status_page("job-id", PaginationOptions {
+    ///             limit: Some(100),
+    ///             ..Default::default()
+    ///         })
+    ///         .await?;
+    ///     println!("Documents: {}", page.data.len());
+    ///
+    ///     while let Some(next) = page.next.take() {
+    ///         page = client.get_crawl_status_page_next(&next).await?;
+    ///         println!("Documents: {}", page.data.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_crawl_status_page(
+        &self,
+        id: impl AsRef<str>,
+        pagination: impl Into<Option<PaginationOptions>>,
+    ) -> Result<CrawlJob, FirecrawlError> {
+        let pagination = pagination.into().unwrap_or_default();
+
+        let response = self
+            .client
+            .get(self.url(&format!("/crawl/{}", id.as_ref())))
+            .query(&pagination)
+            .headers(self.prepare_headers(None))
+            .send()
+            .await
+            .map_err(|e| {
+                FirecrawlError::HttpError(format!("Checking crawl status {}", id.as_ref()), e)
+            })?;
+
+        self.handle_response(response, format!("crawl status {}", id.as_ref()))
+            .await
+    }
+
+    /// Gets the page of crawl results behind a `next` URL returned by a previous call.
+    pub async fn get_crawl_status_page_next(
+        &self,
+        next: impl AsRef<str>,
+    ) -> Result<CrawlJob, FirecrawlError> {
+        self.get_crawl_status_next(next.as_ref()).await
+    }
+
     /// Fetches the next page of crawl results.
     async fn get_crawl_status_next(&self, next: &str) -> Result<CrawlJob, FirecrawlError> {
         let response = self
@@ -448,6 +525,14 @@ pub(crate) fn convert_v2_document_to_v1_pub(doc: Document) -> crate::document::D
 /// Converts a v2 Document to a v1 Document for error compatibility.
 fn convert_v2_document_to_v1(doc: Document) -> crate::document::Document {
     let metadata = doc.metadata.unwrap_or_default();
+    // v1 has no typed field for these, so keep them as extra metadata instead of dropping them
+    let mut extra = metadata.extra;
+    if let Some(concurrency_limited) = metadata.concurrency_limited {
+        extra.insert(
+            "concurrencyLimited".to_string(),
+            serde_json::Value::Bool(concurrency_limited),
+        );
+    }
     crate::document::Document {
         markdown: doc.markdown,
         html: doc.html,
@@ -457,8 +542,10 @@ fn convert_v2_document_to_v1(doc: Document) -> crate::document::Document {
         extract: doc.json,
         metadata: crate::document::DocumentMetadata {
             source_url: metadata.source_url.unwrap_or_default(),
+            url: metadata.url,
             status_code: metadata.status_code.unwrap_or(0),
             error: metadata.error,
+            content_type: metadata.content_type,
             title: metadata.title,
             description: metadata.description,
             language: metadata.language,
@@ -488,7 +575,27 @@ fn convert_v2_document_to_v1(doc: Document) -> crate::document::Document {
             dc_date: metadata.dc_date,
             dc_date_created: metadata.dc_date_created,
             dcterms_created: metadata.dcterms_created,
+            scrape_id: metadata.scrape_id,
+            num_pages: metadata.num_pages,
+            timezone: metadata.timezone,
+            proxy_used: metadata.proxy_used,
+            cache_state: metadata.cache_state,
+            cached_at: metadata.cached_at,
+            credits_used: metadata.credits_used,
+            extra,
         },
+        change_tracking: doc.change_tracking,
+        actions: doc.actions.and_then(|actions| {
+            serde_json::to_value(actions)
+                .and_then(serde_json::from_value)
+                .inspect_err(|e| {
+                    tracing::warn!(
+                        "Dropping action results that do not fit the v1 format: {}",
+                        e
+                    )
+                })
+                .ok()
+        }),
         warning: doc.warning,
     }
 }
@@ -573,6 +680,58 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_get_crawl_status_page_with_mock() {
+        let mut server = mockito::Server::new_async().await;
+        let next = format!("{}/v2/crawl/crawl-123?skip=2&limit=1", server.url());
+
+        let mock = server
+            .mock("GET", "/v2/crawl/crawl-123")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("skip".into(), "1".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "status": "completed",
+                    "total": 3,
+                    "completed": 3,
+                    "next": next,
+                    "data": [
+                        {
+                            "markdown": "# Page 2",
+                            "metadata": {
+                                "sourceURL": "https://example.com/page2",
+                                "statusCode": 200
+                            }
+                        }
+                    ]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let page = client
+            .get_crawl_status_page(
+                "crawl-123",
+                PaginationOptions {
+                    skip: Some(1),
+                    limit: Some(1),
+                },
+            )
+            .await
+            .unwrap();
+
+        // Completed crawls are not auto-paginated when a page is requested.
+        assert_eq!(page.status, JobStatus::Completed);
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.next.as_deref(), Some(next.as_str()));
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_cancel_crawl_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -616,7 +775,11 @@ mod tests {
                     ],
                     "robotsBlocked": [
                         "https://example.com/admin"
-                    ]
+                    ],
+                    "denialCounts": {
+                        "ROBOTS_TXT": 1,
+                        "EXCLUDE_PATTERN": 12
+                    }
                 })
                 .to_string(),
             )
@@ -628,6 +791,7 @@ mod tests {
         assert_eq!(errors.errors.len(), 1);
         assert_eq!(errors.errors[0].url, "https://example.com/broken");
         assert_eq!(errors.robots_blocked.len(), 1);
+        assert_eq!(errors.denial_counts["EXCLUDE_PATTERN"], 12);
         mock.assert();
     }
 
@@ -685,4 +849,47 @@ mod tests {
         start_mock.assert();
         status_mock.assert();
     }
+
+    #[test]
+    fn test_convert_v2_document_to_v1_actions() {
+        let doc: Document = serde_json::from_value(json!({
+            "metadata": { "sourceURL": "https://example.com", "statusCode": 200 },
+            "actions": {
+                "screenshots": ["https://example.com/shot.png"],
+                "javascriptReturns": [{ "type": "string", "value": "Example" }]
+            }
+        }))
+        .unwrap();
+        let actions = convert_v2_document_to_v1(doc).actions.unwrap();
+        assert_eq!(
+            actions.screenshots,
+            Some(vec!["https://example.com/shot.png".to_string()])
+        );
+        assert_eq!(actions.javascript_returns.unwrap().len(), 1);
+
+        let doc: Document = serde_json::from_value(json!({
+            "actions": { "screenshots": "not-a-list" }
+        }))
+        .unwrap();
+        assert!(convert_v2_document_to_v1(doc).actions.is_none());
+    }
+
+    #[test]
+    fn test_convert_v2_document_to_v1_metadata_extra() {
+        let doc: Document = serde_json::from_value(json!({
+            "metadata": {
+                "sourceURL": "https://example.com",
+                "statusCode": 200,
+                "concurrencyLimited": true,
+                "x-custom": "value"
+            }
+        }))
+        .unwrap();
+        let metadata = convert_v2_document_to_v1(doc).metadata;
+        assert_eq!(metadata.extra["concurrencyLimited"], json!(true));
+        assert_eq!(metadata.extra["x-custom"], json!("value"));
+
+        let serialized = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(serialized["concurrencyLimited"], json!(true));
+    }
 }
@@ -104,6 +104,289 @@ pub enum ParserConfig {
     },
 }
 
+impl ScrapeOptions {
+    /// Starts building `ScrapeOptions` with every field unset, so the API defaults apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use firecrawl::v2::{Format, ScrapeOptions};
+    ///
+    /// let options = ScrapeOptions::builder()
+    ///     .format(Format::Markdown)
+    ///     .format(Format::Links)
+    ///     .only_main_content(true)
+    ///     .timeout(30_000)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(options.formats.map(|f| f.len()), Some(2));
+    /// ```
+    pub fn builder() -> ScrapeOptionsBuilder {
+        ScrapeOptionsBuilder::default()
+    }
+}
+
+/// Smallest timeout the API accepts, in milliseconds.
+const MIN_TIMEOUT_MS: u32 = 1_000;
+/// Largest `waitFor` the API accepts, in milliseconds.
+const MAX_WAIT_FOR_MS: u32 = 60_000;
+
+/// Builder for [`ScrapeOptions`], validating option combinations in [`ScrapeOptionsBuilder::build`].
+#[derive(Debug, Default, Clone)]
+pub struct ScrapeOptionsBuilder {
+    options: ScrapeOptions,
+}
+
+impl ScrapeOptionsBuilder {
+    /// Adds an output format.
+    pub fn format(mut self, format: Format) -> Self {
+        self.options
+            .formats
+            .get_or_insert_with(Vec::new)
+            .push(format);
+        self
+    }
+
+    /// Replaces the output formats.
+    pub fn formats(mut self, formats: impl IntoIterator<Item = Format>) -> Self {
+        self.options.formats = Some(formats.into_iter().collect());
+        self
+    }
+
+    /// Adds an HTTP header to send with the request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options
+            .headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// HTML tags to exclusively include in the output.
+    pub fn include_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.options.include_tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// HTML tags to exclude from the output.
+    pub fn exclude_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.options.exclude_tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only extract the main content of the page.
+    pub fn only_main_content(mut self, only_main_content: bool) -> Self {
+        self.options.only_main_content = Some(only_main_content);
+        self
+    }
+
+    /// Timeout in milliseconds. Must be at least 1000.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Time to wait after page load, in milliseconds. At most 60000, and at most half the timeout.
+    pub fn wait_for(mut self, wait_for: u32) -> Self {
+        self.options.wait_for = Some(wait_for);
+        self
+    }
+
+    /// Emulate a mobile device.
+    pub fn mobile(mut self, mobile: bool) -> Self {
+        self.options.mobile = Some(mobile);
+        self
+    }
+
+    /// Adds a parser configuration (e.g., for PDFs).
+    pub fn parser(mut self, parser: ParserConfig) -> Self {
+        self.options
+            .parsers
+            .get_or_insert_with(Vec::new)
+            .push(parser);
+        self
+    }
+
+    /// Adds a browser action, run after the previously added ones.
+    pub fn action(mut self, action: Action) -> Self {
+        self.options
+            .actions
+            .get_or_insert_with(Vec::new)
+            .push(action);
+        self
+    }
+
+    /// Location configuration for proxy routing.
+    pub fn location(mut self, location: LocationConfig) -> Self {
+        self.options.location = Some(location);
+        self
+    }
+
+    /// Skip TLS certificate verification.
+    pub fn skip_tls_verification(mut self, skip_tls_verification: bool) -> Self {
+        self.options.skip_tls_verification = Some(skip_tls_verification);
+        self
+    }
+
+    /// Remove base64-encoded images from the output.
+    pub fn remove_base64_images(mut self, remove_base64_images: bool) -> Self {
+        self.options.remove_base64_images = Some(remove_base64_images);
+        self
+    }
+
+    /// Enable fast mode for quicker scrapes with reduced accuracy.
+    pub fn fast_mode(mut self, fast_mode: bool) -> Self {
+        self.options.fast_mode = Some(fast_mode);
+        self
+    }
+
+    /// Block advertisements on the page.
+    pub fn block_ads(mut self, block_ads: bool) -> Self {
+        self.options.block_ads = Some(block_ads);
+        self
+    }
+
+    /// Proxy type to use.
+    pub fn proxy(mut self, proxy: ProxyType) -> Self {
+        self.options.proxy = Some(proxy);
+        self
+    }
+
+    /// Maximum age of cached content to accept (seconds).
+    pub fn max_age(mut self, max_age: u32) -> Self {
+        self.options.max_age = Some(max_age);
+        self
+    }
+
+    /// Minimum age of cached content to accept (seconds).
+    pub fn min_age(mut self, min_age: u32) -> Self {
+        self.options.min_age = Some(min_age);
+        self
+    }
+
+    /// Store the result in cache for future requests.
+    pub fn store_in_cache(mut self, store_in_cache: bool) -> Self {
+        self.options.store_in_cache = Some(store_in_cache);
+        self
+    }
+
+    /// Integration identifier for tracking.
+    pub fn integration(mut self, integration: impl Into<String>) -> Self {
+        self.options.integration = Some(integration.into());
+        self
+    }
+
+    /// Sets the JSON extraction options. Requires `Format::Json`.
+    pub fn json_options(mut self, json_options: JsonOptions) -> Self {
+        self.options.json_options = Some(json_options);
+        self
+    }
+
+    /// Sets the screenshot options. Requires `Format::Screenshot`.
+    pub fn screenshot_options(mut self, screenshot_options: ScreenshotOptions) -> Self {
+        self.options.screenshot_options = Some(screenshot_options);
+        self
+    }
+
+    /// Sets the change tracking options. Requires `Format::ChangeTracking`.
+    pub fn change_tracking_options(
+        mut self,
+        change_tracking_options: ChangeTrackingOptions,
+    ) -> Self {
+        self.options.change_tracking_options = Some(change_tracking_options);
+        self
+    }
+
+    /// Adds an attribute selector. Requires `Format::Attributes`.
+    pub fn attribute_selector(mut self, selector: AttributeSelector) -> Self {
+        self.options
+            .attribute_selectors
+            .get_or_insert_with(Vec::new)
+            .push(selector);
+        self
+    }
+
+    /// Validates the options and returns them.
+    ///
+    /// Fails with [`FirecrawlError::Missuse`] when a format-specific option is set without its
+    /// format, a screenshot quality is outside `1..=100`, the timeout is below 1000ms, or
+    /// `wait_for` exceeds 60000ms or half the timeout. These mirror the API's own checks.
+    pub fn build(self) -> Result<ScrapeOptions, FirecrawlError> {
+        let options = self.options;
+        let has_format = |format: Format| {
+            options
+                .formats
+                .as_ref()
+                .is_some_and(|formats| formats.contains(&format))
+        };
+
+        for (set, format, name) in [
+            (options.json_options.is_some(), Format::Json, "json_options"),
+            (
+                options.screenshot_options.is_some(),
+                Format::Screenshot,
+                "screenshot_options",
+            ),
+            (
+                options.change_tracking_options.is_some(),
+                Format::ChangeTracking,
+                "change_tracking_options",
+            ),
+            (
+                options.attribute_selectors.is_some(),
+                Format::Attributes,
+                "attribute_selectors",
+            ),
+        ] {
+            if set && !has_format(format) {
+                return Err(FirecrawlError::Missuse(format!(
+                    "{} requires {:?} in formats",
+                    name, format
+                )));
+            }
+        }
+
+        if let Some(quality) = options.screenshot_options.as_ref().and_then(|s| s.quality) {
+            if !(1..=100).contains(&quality) {
+                return Err(FirecrawlError::Missuse(format!(
+                    "Screenshot quality must be between 1 and 100, got {}",
+                    quality
+                )));
+            }
+        }
+
+        if options
+            .timeout
+            .is_some_and(|timeout| timeout < MIN_TIMEOUT_MS)
+        {
+            return Err(FirecrawlError::Missuse(format!(
+                "Timeout must be at least {}ms",
+                MIN_TIMEOUT_MS
+            )));
+        }
+
+        if let Some(wait_for) = options.wait_for {
+            if wait_for > MAX_WAIT_FOR_MS {
+                return Err(FirecrawlError::Missuse(format!(
+                    "wait_for must not exceed {}ms",
+                    MAX_WAIT_FOR_MS
+                )));
+            }
+            if options
+                .timeout
+                .is_some_and(|timeout| u64::from(wait_for) * 2 > u64::from(timeout))
+            {
+                return Err(FirecrawlError::Missuse(
+                    "wait_for must not exceed half of the timeout".to_string(),
+                ));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
 /// Request body for scrape endpoint.
 #[derive(Deserialize, Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -255,6 +538,67 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn test_scrape_options_builder() {
+        let options = ScrapeOptions::builder()
+            .format(Format::Markdown)
+            .format(Format::Json)
+            .json_options(JsonOptions {
+                prompt: Some("Extract the title".to_string()),
+                ..Default::default()
+            })
+            .header("User-Agent", "test")
+            .action(Action::Scrape)
+            .timeout(30_000)
+            .wait_for(1_000)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "formats": ["markdown", "json"],
+                "headers": { "User-Agent": "test" },
+                "actions": [{ "type": "scrape" }],
+                "timeout": 30000,
+                "waitFor": 1000,
+                "jsonOptions": { "prompt": "Extract the title" }
+            })
+        );
+
+        let missing_format = ScrapeOptions::builder()
+            .screenshot_options(ScreenshotOptions::default())
+            .build();
+        assert!(matches!(missing_format, Err(FirecrawlError::Missuse(_))));
+
+        let bad_quality = ScrapeOptions::builder()
+            .format(Format::Screenshot)
+            .screenshot_options(ScreenshotOptions {
+                quality: Some(0),
+                ..Default::default()
+            })
+            .build();
+        assert!(matches!(bad_quality, Err(FirecrawlError::Missuse(_))));
+
+        let timeout_too_short = ScrapeOptions::builder().timeout(999).build();
+        assert!(matches!(timeout_too_short, Err(FirecrawlError::Missuse(_))));
+
+        let wait_too_long = ScrapeOptions::builder()
+            .timeout(2_000)
+            .wait_for(1_001)
+            .build();
+        assert!(matches!(wait_too_long, Err(FirecrawlError::Missuse(_))));
+
+        let wait_half_timeout = ScrapeOptions::builder()
+            .timeout(2_000)
+            .wait_for(1_000)
+            .build();
+        assert!(wait_half_timeout.is_ok());
+
+        let wait_over_max = ScrapeOptions::builder().wait_for(60_001).build();
+        assert!(matches!(wait_over_max, Err(FirecrawlError::Missuse(_))));
+    }
+
     #[tokio::test]
     async fn test_scrape_with_mock() {
         let mut server = mockito::Server::new_async().await;
@@ -287,6 +631,73 @@ mod tests {
         mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_scrape_metadata_url() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "metadata": {
+                            "sourceURL": "https://example.com",
+                            "url": "https://www.example.com/",
+                            "statusCode": 200
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let document = client.scrape("https://example.com", None).await.unwrap();
+
+        let metadata = document.metadata.unwrap();
+        assert_eq!(metadata.source_url.as_deref(), Some("https://example.com"));
+        assert_eq!(metadata.url.as_deref(), Some("https://www.example.com/"));
+        assert!(!metadata.extra.contains_key("url"));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_scrape_metadata_extra() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": true,
+                    "data": {
+                        "metadata": {
+                            "sourceURL": "https://example.com",
+                            "statusCode": 200,
+                            "concurrencyLimited": true,
+                            "x-custom": "value"
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let document = client.scrape("https://example.com", None).await.unwrap();
+
+        let metadata = document.metadata.unwrap();
+        assert_eq!(metadata.concurrency_limited, Some(true));
+        assert_eq!(metadata.extra.len(), 1);
+        assert_eq!(metadata.extra["x-custom"], json!("value"));
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_scrape_with_options() {
         let mut server = mockito::Server::new_async().await;
@@ -401,4 +812,35 @@ mod tests {
         assert!(result.is_err());
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_scrape_error_code() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/v2/scrape")
+            .with_status(408)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "success": false,
+                    "code": "SCRAPE_TIMEOUT",
+                    "error": "Scrape timed out"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new_selfhosted(server.url(), Some("test_key")).unwrap();
+        let result = client.scrape("https://example.com", None).await;
+
+        match result {
+            Err(FirecrawlError::APIError(_, error)) => {
+                assert_eq!(error.code.as_deref(), Some("SCRAPE_TIMEOUT"));
+                assert_eq!(error.error, "Scrape timed out");
+            }
+            other => panic!("Expected APIError, got {:?}", other),
+        }
+        mock.assert();
+    }
 }
@@ -303,6 +303,8 @@ pub struct DocumentMetadata {
     // Firecrawl specific
     #[serde(rename = "sourceURL")]
     pub source_url: Option<String>,
+    /// The URL the page was finally loaded from, after following redirects.
+    pub url: Option<String>,
     pub status_code: Option<u16>,
     pub error: Option<String>,
 
@@ -353,6 +355,10 @@ pub struct DocumentMetadata {
     pub cached_at: Option<String>,
     pub credits_used: Option<u32>,
     pub concurrency_limited: Option<bool>,
+
+    /// Any other metadata returned by the API, such as meta tags without a dedicated field.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Extracted attribute result.
@@ -507,4 +513,7 @@ pub struct CrawlErrorsResponse {
     pub errors: Vec<CrawlError>,
     #[serde(rename = "robotsBlocked")]
     pub robots_blocked: Vec<String>,
+    /// Number of discovered URLs the crawler skipped, per denial reason (e.g. `ROBOTS_TXT`).
+    #[serde(default)]
+    pub denial_counts: HashMap<String, u32>,
 }